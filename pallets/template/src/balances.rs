@@ -0,0 +1,34 @@
+//! Operações de saldo do pallet.
+//!
+//! Todo movimento e consulta de fundos passa por aqui, para que trocar `fungible` por outra
+//! abstração de moeda mude apenas este módulo.
+
+use super::*;
+use frame_support::traits::{
+    fungible::{Inspect, Mutate},
+    tokens::{Fortitude, Preservation},
+};
+
+/// Moeda usada pelo pallet, como configurada em `NativeBalance`.
+pub type Currency<T> = <T as Config>::NativeBalance;
+
+impl<T: Config> Pallet<T> {
+    /// Transfere `amount` de `from` para `to`, respeitando `preservation` na conta de origem.
+    pub(crate) fn transfer_funds(
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: BalanceOf<T>,
+        preservation: Preservation,
+    ) -> DispatchResult {
+        Currency::<T>::transfer(from, to, amount, preservation)?;
+        Ok(())
+    }
+
+    /// Quanto `who` consegue gastar agora sem violar `preservation`.
+    pub(crate) fn spendable_balance(
+        who: &T::AccountId,
+        preservation: Preservation,
+    ) -> BalanceOf<T> {
+        Currency::<T>::reducible_balance(who, preservation, Fortitude::Polite)
+    }
+}
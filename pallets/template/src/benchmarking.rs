@@ -5,30 +5,119 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Template;
 use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+
+/// DNA determinístico e distinto para o `i`-ésimo Kitty de um benchmark.
+fn bench_dna<T: Config>(i: u32) -> DnaOf<T> {
+	let index = i.to_le_bytes();
+	Dna::from_fn(|byte| index.get(byte).copied().unwrap_or(0))
+}
+
+/// Dá a `who` saldo para pagar o `MintDeposit` de `mints` criações e continuar vivo.
+fn fund_minter<T: Config>(who: &T::AccountId, mints: u32) {
+	let minimum = T::NativeBalance::minimum_balance();
+	let deposits = T::MintDeposit::get().saturating_mul(mints.into());
+	T::NativeBalance::set_balance(who, minimum.saturating_add(deposits));
+	// A conta do pallet guarda os depósitos, então precisa existir antes de recebê-los.
+	let pallet = Pallet::<T>::account_id();
+	if T::NativeBalance::balance(&pallet) < minimum {
+		T::NativeBalance::set_balance(&pallet, minimum);
+	}
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
+	/// Transferência de um Kitty. Com a posse em `OwnedKitties`, o custo não depende de quantos
+	/// Kitties o remetente tem.
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32;
+	fn transfer() {
 		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		fund_minter::<T>(&caller, 1);
+		let kitty_id = bench_dna::<T>(0);
+		Pallet::<T>::mint(caller.clone(), kitty_id.clone()).unwrap();
+
+		#[extrinsic_call]
+		transfer(RawOrigin::Signed(caller.clone()), recipient.clone(), kitty_id.clone(), None);
+
+		assert_eq!(Kitties::<T>::get(&kitty_id).unwrap().owner, recipient);
+		assert_eq!(OwnedCount::<T>::get(&caller), 0);
+	}
+
+	/// `buy_kitty` sem taxas. A posse em `OwnedKitties` não depende do tamanho, então não há
+	/// componentes.
+	#[benchmark]
+	fn buy_kitty() {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let buyer: T::AccountId = whitelisted_caller();
+		let price: BalanceOf<T> = T::NativeBalance::minimum_balance().saturating_mul(10u32.into());
+		fund_minter::<T>(&seller, 1);
+		T::NativeBalance::set_balance(&buyer, price.saturating_mul(2u32.into()));
+
+		let kitty_id = bench_dna::<T>(0);
+		Pallet::<T>::mint(seller.clone(), kitty_id.clone()).unwrap();
+		Pallet::<T>::do_set_price(seller.clone(), kitty_id.clone(), Some(price)).unwrap();
+
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		buy_kitty(
+			RawOrigin::Signed(buyer.clone()),
+			kitty_id.clone(),
+			seller.clone(),
+			price,
+			true,
+			None,
+		);
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(Kitties::<T>::get(&kitty_id).unwrap().owner, buyer);
 	}
 
+	/// `breed_kitty` acrescenta o filho à posse do dono. O pallet não verifica ancestrais e a
+	/// posse em `OwnedKitties` não depende do tamanho, então não há componentes.
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
+	fn breed_kitty() {
 		let caller: T::AccountId = whitelisted_caller();
+		// Os dois pais e o filho.
+		fund_minter::<T>(&caller, 3);
+		// DNAs sem bytes zero, para que o filho nunca coincida com um Kitty de `bench_dna`.
+		let parent_1: DnaOf<T> = Dna::from_fn(|_| 1);
+		let parent_2: DnaOf<T> = Dna::from_fn(|_| 2);
+		Pallet::<T>::mint(caller.clone(), parent_1.clone()).unwrap();
+		Pallet::<T>::mint(caller.clone(), parent_2.clone()).unwrap();
+
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		breed_kitty(RawOrigin::Signed(caller.clone()), parent_1, parent_2, None);
+
+		assert_eq!(OwnedCount::<T>::get(&caller), 3);
+	}
+
+	/// Limpeza de uma única oferta expirada em `on_idle`: a devolução ao comprador e a remoção
+	/// de `Offers`, `OfferCount` e `OfferedTotal`.
+	#[benchmark]
+	fn cleanup_expired_offer() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let bidder: T::AccountId = whitelisted_caller();
+		let amount: BalanceOf<T> = T::NativeBalance::minimum_balance().saturating_mul(10u32.into());
+		T::NativeBalance::set_balance(&bidder, amount.saturating_mul(2u32.into()));
+		let pallet = Pallet::<T>::account_id();
+		T::NativeBalance::set_balance(&pallet, T::NativeBalance::minimum_balance());
+
+		fund_minter::<T>(&owner, 1);
+		let kitty_id = bench_dna::<T>(0);
+		Pallet::<T>::mint(owner, kitty_id.clone()).unwrap();
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		Pallet::<T>::do_make_offer(bidder.clone(), kitty_id.clone(), amount, 2u32.into()).unwrap();
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+		#[block]
+		{
+			Pallet::<T>::cleanup_expired_offers(Weight::MAX);
+		}
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert!(!Offers::<T>::contains_key(&kitty_id, &bidder));
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,115 @@
+//! DNA dos Kitties, que também é o identificador deles.
+//!
+//! O tamanho vem de `Config::DnaLength`. O DNA é codificado como um array, sem prefixo de
+//! tamanho, então com 32 bytes as chaves e os valores gravados são os mesmos de `[u8; 32]`.
+
+use alloc::{vec, vec::Vec};
+use codec::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+use core::{cmp::Ordering, ops::Deref};
+use frame_support::{
+    traits::Get, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::{meta_type, Type, TypeDefArray, TypeInfo};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+/// DNA de exatamente `S::get()` bytes.
+#[derive(CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct Dna<S: Get<u32>>(BoundedVec<u8, S>);
+
+impl<S: Get<u32>> Dna<S> {
+    /// Monta um DNA com `byte(i)` na posição `i`.
+    pub fn from_fn(byte: impl FnMut(usize) -> u8) -> Self {
+        Self(BoundedVec::truncate_from((0..S::get() as usize).map(byte).collect()))
+    }
+
+    /// DNA tirado do `blake2_256` de `seed`. Depois dos primeiros 32 bytes, cada bloco de 32
+    /// vem do hash de `(seed, bloco)`.
+    pub fn hash_of(seed: &impl Encode) -> Self {
+        let len = S::get() as usize;
+        let mut bytes = BlakeTwo256::hash_of(seed).as_ref().to_vec();
+        let mut block = 1u32;
+        while bytes.len() < len {
+            bytes.extend_from_slice(BlakeTwo256::hash_of(&(seed, block)).as_ref());
+            block += 1;
+        }
+        bytes.truncate(len);
+        Self(BoundedVec::truncate_from(bytes))
+    }
+
+    /// Os bytes do DNA.
+    pub fn into_inner(self) -> BoundedVec<u8, S> {
+        self.0
+    }
+}
+
+/// Aceita apenas vetores com exatamente `S::get()` bytes, devolvendo os outros.
+impl<S: Get<u32>> TryFrom<Vec<u8>> for Dna<S> {
+    type Error = Vec<u8>;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Vec<u8>> {
+        if bytes.len() != S::get() as usize {
+            return Err(bytes);
+        }
+        Ok(Self(BoundedVec::truncate_from(bytes)))
+    }
+}
+
+impl<S: Get<u32>> Deref for Dna<S> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<S: Get<u32>> AsRef<[u8]> for Dna<S> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<S: Get<u32>> PartialOrd for Dna<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: Get<u32>> Ord for Dna<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0[..].cmp(&other.0[..])
+    }
+}
+
+impl<S: Get<u32>> Encode for Dna<S> {
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    fn encode_to<W: Output + ?Sized>(&self, dest: &mut W) {
+        dest.write(&self.0);
+    }
+}
+
+impl<S: Get<u32>> EncodeLike for Dna<S> {}
+
+impl<S: Get<u32>> Decode for Dna<S> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = vec![0u8; S::get() as usize];
+        input.read(&mut bytes)?;
+        Ok(Self(BoundedVec::truncate_from(bytes)))
+    }
+}
+
+impl<S: Get<u32>> MaxEncodedLen for Dna<S> {
+    fn max_encoded_len() -> usize {
+        S::get() as usize
+    }
+}
+
+impl<S: Get<u32> + 'static> TypeInfo for Dna<S> {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        TypeDefArray::new(S::get(), meta_type::<u8>()).into()
+    }
+}
@@ -0,0 +1,133 @@
+//! Pontos de extensão para que outros pallets reajam a eventos dos Kitties.
+
+use crate::{Config, Pallet};
+use core::marker::PhantomData;
+use sp_runtime::DispatchResult;
+
+/// Chamado sempre que um novo Kitty é criado.
+pub trait OnNewKitty<AccountId> {
+    fn on_mint(owner: &AccountId, dna: &[u8]);
+}
+
+impl<AccountId> OnNewKitty<AccountId> for () {
+    fn on_mint(_owner: &AccountId, _dna: &[u8]) {}
+}
+
+/// Chamado sempre que um Kitty é vendido, seja por `buy_kitty` ou pela aceitação de uma oferta.
+pub trait OnKittySold<AccountId, Balance> {
+    fn on_sale(buyer: &AccountId, seller: &AccountId, kitty_id: &[u8], price: Balance);
+}
+
+impl<AccountId, Balance> OnKittySold<AccountId, Balance> for () {
+    fn on_sale(_buyer: &AccountId, _seller: &AccountId, _kitty_id: &[u8], _price: Balance) {}
+}
+
+/// Decide quais nomes de Kitty são aceitos por `set_name`.
+pub trait NameValidator {
+    fn validate(name: &[u8]) -> bool;
+}
+
+/// Aceita apenas ASCII imprimível, de `' '` a `'~'`.
+pub struct PrintableAscii;
+
+impl NameValidator for PrintableAscii {
+    fn validate(name: &[u8]) -> bool {
+        name.iter().all(|byte| (b' '..=b'~').contains(byte))
+    }
+}
+
+/// Chamado depois de cada transferência, para que o destinatário possa recusá-la.
+///
+/// Um erro desfaz a transferência inteira. Implementações costumam agir apenas sobre contas de
+/// contratos ou pallets e aceitar as demais.
+pub trait OnKittyReceived<AccountId> {
+    fn on_received(from: &AccountId, to: &AccountId, kitty_id: &[u8]) -> DispatchResult;
+}
+
+impl<AccountId> OnKittyReceived<AccountId> for () {
+    fn on_received(_from: &AccountId, _to: &AccountId, _kitty_id: &[u8]) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Origem de um byte do DNA do filho no cruzamento.
+pub enum Inheritance {
+    /// Copia o byte do primeiro pai.
+    First,
+    /// Copia o byte do segundo pai.
+    Second,
+    /// Usa o valor dado, vindo de nenhum dos pais.
+    Mutation(u8),
+}
+
+/// Decide a herança de cada byte do DNA no cruzamento, permitindo que cada cadeia defina a sua
+/// genética.
+pub trait TraitMixer {
+    /// Origem do byte `index` do filho, dado o byte `seed` correspondente de um DNA aleatório.
+    fn inherit(index: usize, seed: u8) -> Inheritance;
+}
+
+/// Herda do primeiro pai quando `seed` é par e do segundo quando é ímpar, sem mutações.
+pub struct EvenOddSplit;
+
+impl TraitMixer for EvenOddSplit {
+    fn inherit(_index: usize, seed: u8) -> Inheritance {
+        if seed % 2 == 0 {
+            Inheritance::First
+        } else {
+            Inheritance::Second
+        }
+    }
+}
+
+/// Consulta a posse de itens de outro pallet, que podem ser equipados nos Kitties.
+pub trait InspectItems<AccountId, ItemId> {
+    /// Dono do item, ou `None` se ele não existe.
+    fn owner_of(item: &ItemId) -> Option<AccountId>;
+}
+
+/// Sem pallet de itens: nenhum item existe.
+impl<AccountId, ItemId> InspectItems<AccountId, ItemId> for () {
+    fn owner_of(_item: &ItemId) -> Option<AccountId> {
+        None
+    }
+}
+
+/// Decide quais DNAs podem existir, para que cada cadeia bloqueie padrões ofensivos ou
+/// reservados.
+pub trait DnaFilter {
+    fn is_allowed(dna: &[u8]) -> bool;
+}
+
+impl DnaFilter for () {
+    fn is_allowed(_dna: &[u8]) -> bool {
+        true
+    }
+}
+
+/// Decide para qual conta vão as taxas cobradas pelo pallet.
+pub trait FeeResolver<AccountId> {
+    fn fee_destination() -> AccountId;
+}
+
+/// Acumula as taxas numa subconta do `PalletId`, separada dos fundos das ofertas, de onde a
+/// origem root as retira com `withdraw_fees`.
+pub struct PalletFeeAccount<T>(PhantomData<T>);
+
+impl<T: Config> FeeResolver<T::AccountId> for PalletFeeAccount<T> {
+    fn fee_destination() -> T::AccountId {
+        Pallet::<T>::fee_account()
+    }
+}
+
+/// Valida cada transferência antes de o Kitty mudar de dono, para que cadeias reguladas exijam
+/// KYC ou uma lista de destinatários permitidos.
+pub trait TransferValidator<AccountId> {
+    fn validate(from: &AccountId, to: &AccountId, kitty_id: &[u8]) -> DispatchResult;
+}
+
+impl<AccountId> TransferValidator<AccountId> for () {
+    fn validate(_from: &AccountId, _to: &AccountId, _kitty_id: &[u8]) -> DispatchResult {
+        Ok(())
+    }
+}
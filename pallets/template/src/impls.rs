@@ -1,89 +1,935 @@
 use super::*;
-use frame_support::{
-    pallet_prelude::*,
-    traits::tokens::Preservation,
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, traits::tokens::Preservation};
+use sp_runtime::{
+    traits::{
+        AccountIdConversion, BlakeTwo256, Hash, One, Saturating, TrailingZeroInput,
+        UniqueSaturatedInto, Zero,
+    },
+    PerThing,
 };
-use sp_runtime::traits::BlakeTwo256;
 
 impl<T: Config> Pallet<T> {
-    /// Gera um DNA único para o Kitty usando uma combinação de dados exclusivos.
-    pub fn gen_dna() -> [u8; 32] {
+    /// Resolve a conta que executa uma chamada de jogo.
+    ///
+    /// Origens assinadas agem por si mesmas e não podem indicar `on_behalf_of`. A origem root
+    /// precisa indicar a conta em nome da qual age.
+    pub fn ensure_caller(
+        origin: OriginFor<T>,
+        on_behalf_of: Option<T::AccountId>,
+    ) -> Result<T::AccountId, DispatchError> {
+        match (ensure_signed_or_root(origin)?, on_behalf_of) {
+            (Some(who), None) => Ok(who),
+            (Some(_), Some(_)) => Err(Error::<T>::OnBehalfOfNotAllowed.into()),
+            (None, Some(who)) => Ok(who),
+            (None, None) => Err(Error::<T>::MissingOnBehalfOf.into()),
+        }
+    }
+
+    /// Registra que `who` esteve ativo no bloco atual.
+    pub fn note_activity(who: &T::AccountId) {
+        LastActivity::<T>::insert(who, frame_system::Pallet::<T>::block_number());
+    }
+
+    /// Reatribui o Kitty de um dono inativo há mais de `InactivityThreshold` blocos.
+    ///
+    /// Contas sem atividade registrada contam como inativas desde o bloco zero.
+    pub fn do_claim_inactive(kitty_id: DnaOf<T>, new_owner: T::AccountId) -> DispatchResult {
+        let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+        let last_activity = LastActivity::<T>::get(&kitty.owner).unwrap_or_else(Zero::zero);
+        let now = frame_system::Pallet::<T>::block_number();
+        ensure!(
+            now.saturating_sub(last_activity) > T::InactivityThreshold::get(),
+            Error::<T>::OwnerStillActive
+        );
+
+        let from = kitty.owner;
+        Self::do_transfer(
+            from.clone(),
+            new_owner.clone(),
+            kitty_id.clone(),
+            TransferReason::Force,
+        )?;
+        Self::deposit_event(Event::<T>::Claimed { kitty_id, from, to: new_owner });
+        Ok(())
+    }
+
+    /// Reatribui um Kitty cujo dono não existe mais em `frame_system` e, sem conta, não
+    /// consegue assinar para movê-lo.
+    pub fn do_rehome(kitty_id: DnaOf<T>, new_owner: T::AccountId) -> DispatchResult {
+        let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+        ensure!(
+            !frame_system::Pallet::<T>::account_exists(&kitty.owner),
+            Error::<T>::OwnerAccountExists
+        );
+
+        let from = kitty.owner;
+        Self::do_transfer(
+            from.clone(),
+            new_owner.clone(),
+            kitty_id.clone(),
+            TransferReason::Force,
+        )?;
+        Self::deposit_event(Event::<T>::Rehomed { kitty_id, from, to: new_owner });
+        Ok(())
+    }
+
+    /// Define à força o dono de um Kitty e reconstrói a posse de todas as contas envolvidas,
+    /// mesmo que `OwnedKitties` e `OwnedCount` estivessem inconsistentes.
+    ///
+    /// Não confere `MaxKittiesOwned` nem bloqueios de transferência, e pode ser repetida sem
+    /// mudar um estado já consistente. Varre todo o `OwnedKitties`, então é só para reparos.
+    pub fn do_force_set_owner(kitty_id: DnaOf<T>, new_owner: T::AccountId) -> DispatchResult {
+        let mut kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+        let old_owner = core::mem::replace(&mut kitty.owner, new_owner.clone());
+
+        let mut touched = Self::accounts_holding(&kitty_id);
+        for holder in &touched {
+            OwnedKitties::<T>::remove(holder, &kitty_id);
+        }
+        OwnedKitties::<T>::insert(&new_owner, &kitty_id, ());
+        touched.extend([old_owner.clone(), new_owner.clone()]);
+        for who in &touched {
+            Self::recount_owned(who);
+        }
+
+        // Como numa transferência, o que o dono anterior combinou sobre o Kitty é desfeito.
+        if old_owner != new_owner {
+            if kitty.price.take().is_some() {
+                Self::release_listing(&old_owner);
+            }
+            if let Some(item_id) = kitty.equipped.take() {
+                Self::release_item(&old_owner, &kitty_id, item_id);
+            }
+            SireListings::<T>::remove(&kitty_id);
+            Reservations::<T>::remove(&kitty_id);
+            PrivateSales::<T>::remove(&kitty_id);
+            Approvals::<T>::remove(&kitty_id);
+            StepAuctions::<T>::remove(&kitty_id);
+            PendingBurns::<T>::remove(&kitty_id);
+        }
+        Kitties::<T>::insert(&kitty_id, kitty);
+
+        Self::deposit_event(Event::<T>::OwnerRepaired { kitty_id, old_owner, new_owner });
+        Ok(())
+    }
+
+    /// Apaga o estado por conta de `who`, que não pode possuir nenhum Kitty.
+    ///
+    /// Só apaga o que a própria conta controla. `OwnedLimits` fica, pois é definido pela origem
+    /// root; `MintNonce` também, para que `gen_dna` não repita sementes; e `OfferedTotal`
+    /// acompanha fundos ainda guardados em ofertas. `OwnedCount` e `ListingCount` já não
+    /// existem para uma conta sem Kitties.
+    pub fn do_purge_account(who: T::AccountId) -> DispatchResult {
+        ensure!(OwnedCount::<T>::get(&who) == 0, Error::<T>::NonEmptyAccount);
+
+        LastActivity::<T>::remove(&who);
+
+        Self::deposit_event(Event::<T>::AccountPurged { who });
+        Ok(())
+    }
+
+    /// Recalcula `OwnedCount` e `OwnerCount` de `who` a partir de `OwnedKitties`.
+    fn recount_owned(who: &T::AccountId) {
+        let before = OwnedCount::<T>::get(who);
+        let after = OwnedKitties::<T>::iter_key_prefix(who).count() as u32;
+        match (before == 0, after == 0) {
+            (true, false) => OwnerCount::<T>::mutate(|count| *count = count.saturating_add(1)),
+            (false, true) => OwnerCount::<T>::mutate(|count| *count = count.saturating_sub(1)),
+            _ => {},
+        }
+        if after == 0 {
+            OwnedCount::<T>::remove(who);
+        } else {
+            OwnedCount::<T>::insert(who, after);
+        }
+    }
+
+    /// Altera um Kitty de `who` com `f` numa única leitura e escrita de `Kitties`.
+    ///
+    /// Falha com `NoKitty` ou `NotOwner` antes de chamar `f`; se `f` falhar, o Kitty não é
+    /// gravado.
+    pub fn try_mutate_owned<R>(
+        kitty_id: &DnaOf<T>,
+        who: &T::AccountId,
+        f: impl FnOnce(&mut Kitty<T>) -> Result<R, DispatchError>,
+    ) -> Result<R, DispatchError> {
+        Kitties::<T>::try_mutate(kitty_id, |maybe_kitty| {
+            let kitty = maybe_kitty.as_mut().ok_or(Error::<T>::NoKitty)?;
+            ensure!(kitty.owner == *who, Error::<T>::NotOwner);
+            f(kitty)
+        })
+    }
+
+    /// Carrega um Kitty e garante que `who` é o seu dono.
+    pub fn ensure_owner(
+        kitty_id: &DnaOf<T>,
+        who: &T::AccountId,
+    ) -> Result<Kitty<T>, DispatchError> {
+        let kitty = Kitties::<T>::get(kitty_id).ok_or(Error::<T>::NoKitty)?;
+        ensure!(kitty.owner == *who, Error::<T>::NotOwner);
+        Ok(kitty)
+    }
+
+    /// Gera um DNA único para um Kitty de `who` usando uma combinação de dados exclusivos.
+    ///
+    /// `who` e o seu `MintNonce` diferenciam contas que criam Kitties no mesmo bloco e na mesma
+    /// posição.
+    pub fn gen_dna(who: &T::AccountId) -> DnaOf<T> {
         let unique_payload = (
             frame_system::Pallet::<T>::parent_hash(),
             frame_system::Pallet::<T>::block_number(),
             frame_system::Pallet::<T>::extrinsic_index(),
             CountForKitties::<T>::get(),
+            who,
+            MintNonce::<T>::get(who),
         );
 
-        BlakeTwo256::hash_of(&unique_payload).into()
+        Dna::hash_of(&unique_payload)
+    }
+
+    /// Calcula o DNA de prova de trabalho de `who` para `nonce`.
+    pub fn pow_dna(who: &T::AccountId, nonce: u64) -> DnaOf<T> {
+        Dna::hash_of(&(who, nonce))
+    }
+
+    /// Tópico dos eventos de um Kitty: o hash do DNA, para clientes leves filtrarem por Kitty.
+    pub fn kitty_topic(dna: &DnaOf<T>) -> T::Hash {
+        T::Hashing::hash_of(dna)
+    }
+
+    /// Verifica se o DNA começa com pelo menos `difficulty` bytes zero.
+    pub fn meets_difficulty(dna: &[u8], difficulty: u8) -> bool {
+        dna.iter().take_while(|byte| **byte == 0).count() >= usize::from(difficulty)
+    }
+
+    /// Combina o DNA de dois pais para um filho de `owner`: `TraitInheritance` decide a origem
+    /// de cada byte do filho a partir do byte correspondente de um DNA aleatório.
+    pub fn breed_dna(owner: &T::AccountId, parent_1: &DnaOf<T>, parent_2: &DnaOf<T>) -> DnaOf<T> {
+        let seed = Self::gen_dna(owner);
+        Dna::from_fn(|i| match T::TraitInheritance::inherit(i, seed[i]) {
+            Inheritance::First => parent_1[i],
+            Inheritance::Second => parent_2[i],
+            Inheritance::Mutation(value) => value,
+        })
+    }
+
+    /// Prevê o DNA e as características do filho de dois Kitties existentes, sem alterar o
+    /// armazenamento. Retorna `None` se algum pai não existe ou se os pais são o mesmo Kitty.
+    ///
+    /// A previsão usa a entropia do bloco atual: o cruzamento real só terá o mesmo resultado se
+    /// acontecer neste bloco, na mesma posição e sem outras criações antes dele.
+    pub fn dry_run_breed(
+        parent_1: &DnaOf<T>,
+        parent_2: &DnaOf<T>,
+    ) -> Option<(DnaOf<T>, KittyTraits)> {
+        if parent_1 == parent_2 || !Kitties::<T>::contains_key(parent_2) {
+            return None;
+        }
+        let owner = Kitties::<T>::get(parent_1)?.owner;
+        let dna = Self::breed_dna(&owner, parent_1, parent_2);
+        let traits = decode_traits(&dna);
+        Some((dna, traits))
+    }
+
+    /// Deriva a espécie de um Kitty a partir do seu DNA.
+    pub fn species_of(dna: &[u8]) -> u8 {
+        traits::species_of(dna)
+    }
+
+    /// Retorna até `limit` Kitties da espécie `species`, pulando os `start` primeiros.
+    pub fn kitties_of_species(species: u8, start: u32, limit: u32) -> Vec<DnaOf<T>> {
+        SpeciesIndex::<T>::iter_key_prefix(species)
+            .skip(start as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Retorna até `limit` Kitties com todos os seus dados, começando logo depois de `start`, e
+    /// a chave de onde a próxima página continua, ou `None` na última página.
+    ///
+    /// Segue a ordem de armazenamento de `Kitties`, então as páginas são determinísticas e,
+    /// concatenadas, cobrem cada Kitty exatamente uma vez. `limit` é limitado por `MaxScan`.
+    pub fn snapshot(
+        start: Option<DnaOf<T>>,
+        limit: u32,
+    ) -> (Vec<(DnaOf<T>, KittyDetailsOf<T>)>, Option<DnaOf<T>>) {
+        let mut iter = match start {
+            Some(last) => Kitties::<T>::iter_from(Kitties::<T>::hashed_key_for(last)),
+            None => Kitties::<T>::iter(),
+        };
+
+        let limit = limit.min(T::MaxScan::get()) as usize;
+        let page: Vec<_> =
+            iter.by_ref().take(limit).map(|(kitty_id, kitty)| (kitty_id, kitty.into())).collect();
+        // Só devolve o cursor se ainda houver Kitties depois da página.
+        let next = match page.last() {
+            Some((last, _)) if iter.next().is_some() => Some(last.clone()),
+            _ => None,
+        };
+        (page, next)
+    }
+
+    /// Retorna quantos blocos faltam para o Kitty poder cruzar novamente, ou `None` se ele já
+    /// pode cruzar ou não existe.
+    pub fn cooldown_remaining(kitty_id: &DnaOf<T>) -> Option<BlockNumberFor<T>> {
+        let kitty = Kitties::<T>::get(kitty_id)?;
+        let now = frame_system::Pallet::<T>::block_number();
+        (kitty.cooldown_until > now).then(|| kitty.cooldown_until - now)
+    }
+
+    /// Limite de Kitties que a conta pode possuir, considerando o limite personalizado.
+    pub fn owned_limit(who: &T::AccountId) -> u32 {
+        OwnedLimits::<T>::get(who).unwrap_or_else(T::MaxKittiesOwned::get)
+    }
+
+    /// Retorna quantos Kitties a conta possui e quantos ela pode possuir.
+    pub fn owned_capacity(who: &T::AccountId) -> (u32, u32) {
+        (OwnedCount::<T>::get(who), Self::owned_limit(who))
+    }
+
+    /// Registra `kitty_id` como posse de `who`, respeitando o limite de posse.
+    fn add_owned(who: &T::AccountId, kitty_id: &DnaOf<T>) -> DispatchResult {
+        let (used, limit) = Self::owned_capacity(who);
+        ensure!(used < limit, Error::<T>::TooManyOwned);
+
+        OwnedKitties::<T>::insert(who, kitty_id, ());
+        OwnedCount::<T>::insert(who, used + 1);
+        if used == 0 {
+            OwnerCount::<T>::mutate(|count| *count = count.saturating_add(1));
+        }
+        Ok(())
+    }
+
+    /// Retira `kitty_id` da posse de `who`.
+    fn remove_owned(who: &T::AccountId, kitty_id: &DnaOf<T>) -> DispatchResult {
+        ensure!(OwnedKitties::<T>::contains_key(who, kitty_id), Error::<T>::NoKitty);
+
+        OwnedKitties::<T>::remove(who, kitty_id);
+        match OwnedCount::<T>::get(who).saturating_sub(1) {
+            0 => {
+                OwnedCount::<T>::remove(who);
+                OwnerCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+            },
+            remaining => OwnedCount::<T>::insert(who, remaining),
+        }
+        Ok(())
+    }
+
+    /// Retorna quantos Kitties `to` ainda pode receber, considerando o limite personalizado.
+    ///
+    /// Um limite reduzido abaixo da posse atual resulta em zero.
+    pub fn recipient_remaining_slots(to: &T::AccountId) -> u32 {
+        let (used, limit) = Self::owned_capacity(to);
+        limit.saturating_sub(used)
+    }
+
+    /// Retorna o `index`-ésimo Kitty de `owner`, na ordem de armazenamento, se existir.
+    ///
+    /// A ordem depende do hash dos DNAs, então o mesmo índice pode apontar para outro Kitty
+    /// depois que a posse de `owner` muda. Remover um Kitty não reordena os demais: a ordem
+    /// relativa dos que ficam é sempre a mesma.
+    pub fn kitty_of_owner_by_index(owner: &T::AccountId, index: u32) -> Option<DnaOf<T>> {
+        OwnedKitties::<T>::iter_key_prefix(owner).nth(index as usize)
+    }
+
+    /// Retorna os Kitties de `account`, na ordem de armazenamento.
+    pub fn owned_ids(account: &T::AccountId) -> Vec<DnaOf<T>> {
+        OwnedKitties::<T>::iter_key_prefix(account).collect()
+    }
+
+    /// Retorna o preço anunciado do Kitty, ou zero se ele não está à venda ou não existe.
+    pub fn price_or_zero(kitty_id: &DnaOf<T>) -> BalanceOf<T> {
+        Kitties::<T>::get(kitty_id)
+            .and_then(|kitty| Self::listed_price(kitty_id, &kitty))
+            .unwrap_or_else(Zero::zero)
+    }
+
+    /// Retorna o nome do Kitty, ou um nome vazio se ele não tem nome ou não existe.
+    pub fn name_or_empty(kitty_id: &DnaOf<T>) -> Vec<u8> {
+        KittyNames::<T>::get(kitty_id).map(|name| name.into_inner()).unwrap_or_default()
+    }
+
+    /// Soma os preços dos Kitties anunciados de `account`, saturando em vez de transbordar.
+    ///
+    /// Percorre apenas a posse da conta, que é limitada por `MaxKittiesOwned`.
+    pub fn listed_value(account: &T::AccountId) -> BalanceOf<T> {
+        OwnedKitties::<T>::iter_key_prefix(account)
+            .filter_map(|kitty_id| {
+                Kitties::<T>::get(&kitty_id).and_then(|kitty| Self::listed_price(&kitty_id, &kitty))
+            })
+            .fold(Zero::zero(), |total: BalanceOf<T>, price| total.saturating_add(price))
+    }
+
+    /// Retorna o bloco e o preço das últimas vendas do Kitty, da mais antiga para a mais recente.
+    pub fn price_history(kitty_id: &DnaOf<T>) -> Vec<(BlockNumberFor<T>, BalanceOf<T>)> {
+        PriceHistory::<T>::get(kitty_id).into_inner()
+    }
+
+    /// Retorna até `limit` Kitties anunciados de `owner`, com os seus preços, pulando os `start`
+    /// primeiros anúncios, na ordem de armazenamento.
+    pub fn listings_of(
+        owner: &T::AccountId,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(DnaOf<T>, BalanceOf<T>)> {
+        OwnedKitties::<T>::iter_key_prefix(owner)
+            .filter_map(|kitty_id| {
+                let kitty = Kitties::<T>::get(&kitty_id)?;
+                let price = Self::listed_price(&kitty_id, &kitty)?;
+                Some((kitty_id, price))
+            })
+            .skip(start as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Retorna o Kitty à venda com o menor preço e esse preço.
+    ///
+    /// Não há índice ordenado de anúncios: a busca percorre no máximo `MaxScan` Kitties na ordem
+    /// do armazenamento, então com mais Kitties do que isso o resultado pode não ser o piso real.
+    ///
+    /// Entre anúncios com o mesmo menor preço, vence o de menor DNA, para que consultas
+    /// repetidas retornem sempre o mesmo Kitty.
+    pub fn floor_listing() -> Option<(DnaOf<T>, BalanceOf<T>)> {
+        Kitties::<T>::iter()
+            .take(T::MaxScan::get() as usize)
+            .filter_map(|(kitty_id, kitty)| {
+                Self::listed_price(&kitty_id, &kitty).map(|price| (kitty_id, price))
+            })
+            .min_by(|(id_a, price_a), (id_b, price_b)| (price_a, id_a).cmp(&(price_b, id_b)))
+    }
+
+    /// Compra os anúncios mais baratos para `buyer`, retornando quantas compras foram tentadas.
+    ///
+    /// Para ao comprar `max_count` Kitties, limitado a `MaxBatchSize`, quando a próxima compra
+    /// levaria o total gasto além de `max_total_spend`, ou na primeira compra que falhar, por
+    /// exemplo por falta de saldo, de espaço na posse ou por `TransferValidator`. As compras
+    /// anteriores são mantidas.
+    pub fn do_sweep(
+        buyer: T::AccountId,
+        max_total_spend: BalanceOf<T>,
+        max_count: u32,
+    ) -> Result<u32, DispatchError> {
+        let max_count = max_count.min(T::MaxBatchSize::get());
+        let mut listings: Vec<_> = Kitties::<T>::iter()
+            .take(T::MaxScan::get() as usize)
+            .filter(|(_, kitty)| kitty.owner != buyer && kitty.price.is_some())
+            .filter_map(|(kitty_id, kitty)| {
+                let price = Self::can_buy(&buyer, &kitty_id).ok()?;
+                Some((price, kitty_id, kitty.owner))
+            })
+            .collect();
+        listings.sort_by(|(price_a, id_a, _), (price_b, id_b, _)| {
+            (price_a, id_a).cmp(&(price_b, id_b))
+        });
+
+        let (mut count, mut attempted) = (0u32, 0u32);
+        let mut total_spent = BalanceOf::<T>::zero();
+        for (price, kitty_id, seller) in listings {
+            let next_total = total_spent.saturating_add(price);
+            if count == max_count || next_total > max_total_spend {
+                break;
+            }
+            // `can_buy` confere cada anúncio isoladamente; a compra roda na própria camada de
+            // armazenamento para que uma falha seja desfeita sem perder as anteriores.
+            attempted += 1;
+            let bought = frame_support::storage::with_storage_layer(|| {
+                Self::do_buy_kitty(buyer.clone(), kitty_id, seller, price, true)
+            });
+            if bought.is_err() {
+                break;
+            }
+            total_spent = next_total;
+            count += 1;
+        }
+
+        Self::deposit_event(Event::<T>::Swept { buyer, count, total_spent });
+        Ok(attempted)
+    }
+
+    /// Peso de um `sweep` que tenta `count` compras: a leitura de até `MaxScan` Kitties mais,
+    /// por compra, o pior caso de `buy_kitty`, com a devolução de `MaxOffersPerKitty` ofertas.
+    pub fn sweep_weight(count: u32) -> Weight {
+        let scan = T::DbWeight::get().reads(u64::from(T::MaxScan::get()));
+        let per_buy = T::WeightInfo::buy_kitty()
+            .saturating_add(Self::batch_weight(T::MaxOffersPerKitty::get()));
+        scan.saturating_add(per_buy.saturating_mul(u64::from(count)))
+    }
+
+    /// Define ou remove o limite de posse personalizado de uma conta.
+    pub fn do_set_owned_limit(who: T::AccountId, limit: Option<u32>) -> DispatchResult {
+        match limit {
+            Some(limit) => {
+                ensure!(limit <= T::MaxKittiesOwned::get(), Error::<T>::LimitTooHigh);
+                OwnedLimits::<T>::insert(&who, limit);
+            },
+            None => OwnedLimits::<T>::remove(&who),
+        }
+
+        Self::deposit_event(Event::<T>::OwnedLimitSet { who, limit });
+        Ok(())
+    }
+
+    /// Calcula `percent`% de `amount`, arredondando para baixo e saturando no valor máximo.
+    pub fn percent_of(amount: BalanceOf<T>, percent: u32) -> BalanceOf<T> {
+        let hundred = BalanceOf::<T>::from(100u32);
+        let percent = BalanceOf::<T>::from(percent);
+        // Divide antes de multiplicar para não transbordar com valores altos.
+        (amount / hundred)
+            .saturating_mul(percent)
+            .saturating_add((amount % hundred).saturating_mul(percent) / hundred)
+    }
+
+    /// Peso de uma operação em lote com `count` itens. A soma satura em vez de transbordar, e
+    /// `count` é limitado por `MaxBatchSize` já na decodificação da chamada.
+    pub fn batch_weight(count: u32) -> Weight {
+        let per_item = Weight::from_parts(10_000, 0);
+        Weight::from_parts(10_000, 0).saturating_add(per_item.saturating_mul(u64::from(count)))
+    }
+
+    /// Peso de uma autorização: a leitura do Kitty e a gravação em `Approvals`.
+    pub fn approve_weight() -> Weight {
+        T::DbWeight::get().reads_writes(1, 1)
     }
 
     /// Cria e registra um novo Kitty no armazenamento.
-    pub fn mint(owner: T::AccountId, dna: [u8; 32]) -> DispatchResult {
+    pub fn mint(owner: T::AccountId, dna: DnaOf<T>) -> DispatchResult {
+        let species = Self::species_of(&dna);
         let kitty = Kitty {
-            dna,
+            dna: dna.clone(),
             owner: owner.clone(),
             price: None,
+            species,
+            cooldown_until: Zero::zero(),
+            level: 0,
+            last_fed: Zero::zero(),
+            transfer_locked_until: None,
+            format_version: KITTY_FORMAT_VERSION,
+            equipped: None,
         };
 
-        // Garante que o Kitty com o DNA especificado ainda não existe.
-        ensure!(!Kitties::<T>::contains_key(dna), Error::<T>::DuplicateKitty);
+        // Garante que o Kitty com o DNA especificado ainda não existe e é permitido.
+        ensure!(!Kitties::<T>::contains_key(&dna), Error::<T>::DuplicateKitty);
+        ensure!(T::DnaFilter::is_allowed(&dna), Error::<T>::DnaRejected);
 
         // Incrementa o contador de Kitties.
         let current_count: u32 = CountForKitties::<T>::get();
         let new_count = current_count.checked_add(1).ok_or(Error::<T>::TooManyKitties)?;
+        let max_supply = T::MaxSupply::get();
+        ensure!(new_count <= max_supply, Error::<T>::TooManyKitties);
 
         // Associa o Kitty ao proprietário.
-        KittiesOwned::<T>::try_append(&owner, dna).map_err(|_| Error::<T>::TooManyOwned)?;
-        Kitties::<T>::insert(dna, kitty);
+        Self::add_owned(&owner, &dna)?;
+
+        // Guarda o depósito na conta do pallet.
+        let deposit = T::MintDeposit::get();
+        if !deposit.is_zero() {
+            let pallet = Self::account_id();
+            Self::transfer_funds(&owner, &pallet, deposit, Preservation::Preserve)?;
+            Deposits::<T>::insert(&dna, deposit);
+        }
+
+        Kitties::<T>::insert(&dna, kitty);
+        SpeciesIndex::<T>::insert(species, &dna, ());
         CountForKitties::<T>::set(new_count);
+        MintNonce::<T>::mutate(&owner, |nonce| *nonce = nonce.wrapping_add(1));
+
+        // Emite o evento de criação e avisa quem estiver interessado.
+        T::OnMint::on_mint(&owner, &dna);
+        let topic = Self::kitty_topic(&dna);
+        let event = <T as Config>::RuntimeEvent::from(Event::<T>::Created { owner, kitty_id: dna });
+        frame_system::Pallet::<T>::deposit_event_indexed(&[topic], event.into());
+        if new_count == max_supply {
+            Self::deposit_event(Event::<T>::SupplyCapReached { total: new_count });
+        }
+        Ok(())
+    }
+
+    /// Total de Kitties já criados: os em circulação mais os queimados.
+    pub fn lifetime_minted() -> u32 {
+        CountForKitties::<T>::get().saturating_add(TotalBurned::<T>::get())
+    }
+
+    /// Queima um Kitty, removendo-o de todos os armazenamentos.
+    pub fn do_burn_kitty(caller: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        let kitty = Self::ensure_owner(&kitty_id, &caller)?;
+
+        // Atualiza os armazenamentos.
+        Self::remove_owned(&caller, &kitty_id)?;
+        Kitties::<T>::remove(&kitty_id);
+        SpeciesIndex::<T>::remove(kitty.species, &kitty_id);
+        SireListings::<T>::remove(&kitty_id);
+        Reservations::<T>::remove(&kitty_id);
+        LastSalePrice::<T>::remove(&kitty_id);
+        PriceHistory::<T>::remove(&kitty_id);
+        LastPriceUpdate::<T>::remove(&kitty_id);
+        KittyNames::<T>::remove(&kitty_id);
+        Generations::<T>::remove(&kitty_id);
+        PendingBurns::<T>::remove(&kitty_id);
+        PrivateSales::<T>::remove(&kitty_id);
+        Approvals::<T>::remove(&kitty_id);
+        StepAuctions::<T>::remove(&kitty_id);
+        if let Some(item_id) = kitty.equipped {
+            EquippedItems::<T>::remove(item_id);
+        }
+        if kitty.price.is_some() {
+            Self::release_listing(&caller);
+        }
+        CountForKitties::<T>::mutate(|count| *count = count.saturating_sub(1));
+        TotalBurned::<T>::mutate(|burned| *burned = burned.saturating_add(1));
+
+        // Emite o evento de queima.
+        Self::deposit_event(Event::<T>::Burned {
+            owner: caller.clone(),
+            kitty_id: kitty_id.clone(),
+        });
+        Self::settle_deposit(caller, kitty_id)
+    }
+
+    /// Divide um depósito entre a devolução ao dono e a parte de `FeeResolver`.
+    ///
+    /// Só a devolução é arredondada, para baixo; `FeeResolver` fica com o resto exato, então
+    /// as duas partes sempre somam o depósito, sem sobras nem valores criados.
+    pub fn split_deposit(deposit: BalanceOf<T>) -> (BalanceOf<T>, BalanceOf<T>) {
+        let refund = T::BurnRefundRatio::get().mul_floor(deposit);
+        (refund, deposit - refund)
+    }
+
+    /// Devolve ao dono a fração `BurnRefundRatio` do depósito do Kitty e envia o resto para
+    /// `FeeResolver`. Kitties sem depósito registrado não movem fundos.
+    fn settle_deposit(owner: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        let Some(deposit) = Deposits::<T>::take(&kitty_id) else { return Ok(()) };
+        let (refund, fee) = Self::split_deposit(deposit);
+
+        let pallet = Self::account_id();
+        if !refund.is_zero() {
+            Self::transfer_funds(&pallet, &owner, refund, Preservation::Expendable)?;
+        }
+        if !fee.is_zero() {
+            let destination = T::FeeResolver::fee_destination();
+            Self::transfer_funds(&pallet, &destination, fee, Preservation::Expendable)?;
+        }
+
+        Self::deposit_event(Event::<T>::DepositRefunded { owner, kitty_id, amount: refund });
+        Ok(())
+    }
+
+    /// Define o nome de um Kitty do chamador.
+    pub fn do_set_name(
+        caller: T::AccountId,
+        kitty_id: DnaOf<T>,
+        name: BoundedVec<u8, T::MaxNameLength>,
+    ) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        ensure!(T::NamePolicy::validate(&name), Error::<T>::InvalidName);
 
-        // Emite o evento de criação.
-        Self::deposit_event(Event::<T>::Created { owner });
+        KittyNames::<T>::insert(&kitty_id, name.clone());
+        Self::deposit_event(Event::<T>::NameSet { owner: caller, kitty_id, name });
         Ok(())
     }
 
+    /// Agenda a queima de um Kitty do chamador para daqui a `BurnDelay` blocos.
+    pub fn do_request_burn(caller: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        ensure!(!PendingBurns::<T>::contains_key(&kitty_id), Error::<T>::BurnPending);
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let executable_at = now.saturating_add(T::BurnDelay::get());
+        PendingBurns::<T>::insert(&kitty_id, executable_at);
+
+        Self::deposit_event(Event::<T>::BurnRequested { owner: caller, kitty_id, executable_at });
+        Ok(())
+    }
+
+    /// Cancela a queima pendente de um Kitty do chamador.
+    pub fn do_cancel_burn(caller: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        ensure!(PendingBurns::<T>::take(&kitty_id).is_some(), Error::<T>::NoPendingBurn);
+
+        Self::deposit_event(Event::<T>::BurnCancelled { kitty_id });
+        Ok(())
+    }
+
+    /// Queima um Kitty cuja queima pendente já pode ser executada.
+    pub fn do_finalize_burn(kitty_id: DnaOf<T>) -> DispatchResult {
+        let executable_at = PendingBurns::<T>::get(&kitty_id).ok_or(Error::<T>::NoPendingBurn)?;
+        let now = frame_system::Pallet::<T>::block_number();
+        ensure!(now >= executable_at, Error::<T>::BurnDelayNotElapsed);
+
+        // O dono não muda durante a espera, pois transferências ficam bloqueadas.
+        let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+        Self::do_burn_kitty(kitty.owner, kitty_id)
+    }
+
+    /// Verifica se o bloqueio de transferência do Kitty ainda vale no bloco atual.
+    pub fn is_transfer_locked(kitty: &Kitty<T>) -> bool {
+        let now = frame_system::Pallet::<T>::block_number();
+        kitty.transfer_locked_until.map_or(false, |until| until > now)
+    }
+
+    /// Define ou remove o bloqueio de transferência de um Kitty.
+    pub fn do_lock_transfer(
+        kitty_id: DnaOf<T>,
+        until: Option<BlockNumberFor<T>>,
+    ) -> DispatchResult {
+        Kitties::<T>::try_mutate(&kitty_id, |kitty| -> DispatchResult {
+            let kitty = kitty.as_mut().ok_or(Error::<T>::NoKitty)?;
+            kitty.transfer_locked_until = until;
+            Ok(())
+        })?;
+
+        Self::deposit_event(Event::<T>::TransferLockSet { kitty_id, until });
+        Ok(())
+    }
+
+    /// Libera uma vaga de anúncio de `who`.
+    fn release_listing(who: &T::AccountId) {
+        ListingCount::<T>::mutate_exists(who, |count| {
+            *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+        });
+        ListedCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+    }
+
+    /// Registra uma venda concluída pelo preço `price`.
+    fn record_sale(kitty_id: &DnaOf<T>, price: BalanceOf<T>) {
+        LastSalePrice::<T>::insert(kitty_id, price);
+        let now = frame_system::Pallet::<T>::block_number();
+        PriceHistory::<T>::mutate(kitty_id, |history| {
+            // Com o histórico cheio, descarta a venda mais antiga.
+            if history.is_full() {
+                history.remove(0);
+            }
+            let _ = history.try_push((now, price));
+        });
+        TotalVolume::<T>::mutate(|volume| *volume = volume.saturating_add(price));
+    }
+
+    /// Estatísticas agregadas do mercado, lidas de contadores mantidos a cada operação.
+    ///
+    /// Apenas `floor_price` varre o armazenamento, limitado por `MaxScan`, como
+    /// `floor_listing`.
+    pub fn market_summary() -> MarketSummary<BalanceOf<T>> {
+        MarketSummary {
+            total_kitties: CountForKitties::<T>::get(),
+            total_listed: ListedCount::<T>::get(),
+            floor_price: Self::floor_listing().map(|(_, price)| price),
+            total_volume: TotalVolume::<T>::get(),
+            unique_owners: OwnerCount::<T>::get(),
+        }
+    }
+
+    /// Reúne as constantes de `Config` que as interfaces precisam conhecer.
+    pub fn config_constants() -> ConfigConstants<BalanceOf<T>, BlockNumberFor<T>> {
+        ConfigConstants {
+            max_kitties_owned: T::MaxKittiesOwned::get(),
+            max_supply: T::MaxSupply::get(),
+            max_generation: T::MaxGeneration::get(),
+            max_name_length: T::MaxNameLength::get(),
+            max_memo: T::MaxMemo::get(),
+            max_batch_size: T::MaxBatchSize::get(),
+            max_listings_per_account: T::MaxListingsPerAccount::get(),
+            max_offers_per_kitty: T::MaxOffersPerKitty::get(),
+            max_price_points: T::MaxPricePoints::get(),
+            mint_difficulty: T::MintDifficulty::get(),
+            allow_open_mint: T::AllowOpenMint::get(),
+            breeding_cooldown: T::BreedingCooldown::get(),
+            feed_cooldown: T::FeedCooldown::get(),
+            burn_delay: T::BurnDelay::get(),
+            price_update_cooldown: T::PriceUpdateCooldown::get(),
+            listing_decay: T::ListingDecay::get(),
+            burn_refund_ratio: T::BurnRefundRatio::get(),
+            mint_deposit: T::MintDeposit::get(),
+            accelerate_fee: T::AccelerateFee::get(),
+            min_sire_fee: T::MinSireFee::get(),
+        }
+    }
+
     /// Realiza a transferência de um Kitty entre dois usuários.
-    pub fn do_transfer(from: T::AccountId, to: T::AccountId, kitty_id: [u8; 32]) -> DispatchResult {
+    pub fn do_transfer(
+        from: T::AccountId,
+        to: T::AccountId,
+        kitty_id: DnaOf<T>,
+        reason: TransferReason,
+    ) -> DispatchResult {
+        Self::move_kitty(&from, &to, &kitty_id)?;
+
+        // Emite o evento de transferência com as posses resultantes, poupando consultas aos
+        // indexadores.
+        let from_remaining = OwnedCount::<T>::get(&from);
+        let to_total = OwnedCount::<T>::get(&to);
+        Self::deposit_event(Event::<T>::Transferred {
+            from,
+            to,
+            kitty_id,
+            reason,
+            from_remaining,
+            to_total,
+        });
+        Ok(())
+    }
+
+    /// Transfere um Kitty sem emitir `Transferred`, para quem emite um evento próprio.
+    ///
+    /// O item equipado continua com `from`, então é removido do Kitty com `Unequipped`.
+    fn move_kitty(from: &T::AccountId, to: &T::AccountId, kitty_id: &DnaOf<T>) -> DispatchResult {
         ensure!(from != to, Error::<T>::TransferToSelf);
-        let mut kitty = Kitties::<T>::get(kitty_id).ok_or(Error::<T>::NoKitty)?;
-        ensure!(kitty.owner == from, Error::<T>::NotOwner);
-        kitty.owner = to.clone();
-
-        // Atualiza a lista de Kitties do novo dono.
-        let mut to_owned = KittiesOwned::<T>::get(&to);
-        to_owned.try_push(kitty_id).map_err(|_| Error::<T>::TooManyOwned)?;
-
-        // Remove o Kitty da lista do dono anterior.
-        let mut from_owned = KittiesOwned::<T>::get(&from);
-        if let Some(ind) = from_owned.iter().position(|&id| id == kitty_id) {
-            from_owned.swap_remove(ind);
-        } else {
-            return Err(Error::<T>::NoKitty.into());
+        T::TransferValidator::validate(from, to, kitty_id)?;
+        let (was_listed, equipped) = Self::try_mutate_owned(kitty_id, from, |kitty| {
+            ensure!(!PendingBurns::<T>::contains_key(kitty_id), Error::<T>::BurnPending);
+            ensure!(!Self::is_transfer_locked(kitty), Error::<T>::TransferLocked);
+            kitty.owner = to.clone();
+
+            // Move a posse. O anúncio de venda pertence ao dono anterior.
+            Self::add_owned(to, kitty_id)?;
+            Self::remove_owned(from, kitty_id)?;
+            Ok((kitty.price.take().is_some(), kitty.equipped.take()))
+        })?;
+
+        // A oferta como reprodutor e a reserva também pertencem ao dono anterior.
+        SireListings::<T>::remove(kitty_id);
+        Reservations::<T>::remove(kitty_id);
+        PrivateSales::<T>::remove(kitty_id);
+        Approvals::<T>::remove(kitty_id);
+        StepAuctions::<T>::remove(kitty_id);
+        if was_listed {
+            Self::release_listing(from);
+        }
+        if let Some(item_id) = equipped {
+            Self::release_item(from, kitty_id, item_id);
         }
 
-        // Atualiza os armazenamentos.
-        Kitties::<T>::insert(kitty_id, kitty);
-        KittiesOwned::<T>::insert(&to, to_owned);
-        KittiesOwned::<T>::insert(&from, from_owned);
+        // O destinatário pode recusar, desfazendo a transferência junto com a chamada.
+        T::OnReceive::on_received(from, to, kitty_id)
+    }
+
+    /// Transfere um Kitty como presente e emite o memo logo depois de `Transferred`.
+    pub fn do_transfer_with_memo(
+        from: T::AccountId,
+        to: T::AccountId,
+        kitty_id: DnaOf<T>,
+        memo: BoundedVec<u8, T::MaxMemo>,
+    ) -> DispatchResult {
+        Self::do_transfer(from.clone(), to.clone(), kitty_id.clone(), TransferReason::Gift)?;
+        Self::deposit_event(Event::<T>::TransferredWithMemo { from, to, kitty_id, memo });
+        Ok(())
+    }
+
+    /// Transfere um Kitty e o anuncia por `new_price` em nome do novo dono, que herda o anúncio.
+    pub fn do_transfer_with_price(
+        from: T::AccountId,
+        to: T::AccountId,
+        kitty_id: DnaOf<T>,
+        new_price: BalanceOf<T>,
+    ) -> DispatchResult {
+        Self::do_transfer(from, to.clone(), kitty_id.clone(), TransferReason::Direct)?;
+        Self::do_set_price(to, kitty_id, Some(new_price))?;
+        Ok(())
+    }
+
+    /// Conta derivada de `parent` e `index`, a mesma em qualquer bloco e fora da cadeia.
+    ///
+    /// Segue `pallet_utility::derivative_account_id`: o `blake2_256` de
+    /// `(b"modlpy/utilisuba", parent, index)` codificado em SCALE, decodificado como
+    /// `AccountId`. Assim `Utility::as_derivative` age pela conta, e `parent` também pode
+    /// retirar os Kitties dela com `transfer_from_derived`.
+    pub fn derived_account(parent: &T::AccountId, index: u16) -> T::AccountId {
+        let entropy = BlakeTwo256::hash_of(&(b"modlpy/utilisuba", parent, index));
+        T::AccountId::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+            .expect("entrada infinita de zeros sempre decodifica; qed")
+    }
+
+    /// Transfere um Kitty do chamador para a conta derivada de `parent` e `index`.
+    pub fn do_transfer_to_derived(
+        from: T::AccountId,
+        parent: T::AccountId,
+        index: u16,
+        kitty_id: DnaOf<T>,
+    ) -> DispatchResult {
+        let to = Self::derived_account(&parent, index);
+        Self::do_transfer(from, to, kitty_id, TransferReason::Direct)
+    }
+
+    /// Transfere para `to` um Kitty da conta derivada de `parent` e `index`, em nome dela.
+    pub fn do_transfer_from_derived(
+        parent: T::AccountId,
+        index: u16,
+        kitty_id: DnaOf<T>,
+        to: T::AccountId,
+    ) -> DispatchResult {
+        let from = Self::derived_account(&parent, index);
+        Self::do_transfer(from, to, kitty_id, TransferReason::Direct)
+    }
+
+    /// Transfere vários Kitties de `from` para `to`, falhando por inteiro se qualquer um falhar.
+    ///
+    /// Emite um `Transferred` por Kitty apenas quando `EmitPerItemEvents` está ativo; o resumo
+    /// `BatchTransferred` é sempre emitido.
+    pub fn do_transfer_batch(
+        from: T::AccountId,
+        to: T::AccountId,
+        kitty_ids: BoundedVec<DnaOf<T>, T::MaxBatchSize>,
+    ) -> DispatchResult {
+        let per_item_events = T::EmitPerItemEvents::get();
+        let count = kitty_ids.len() as u32;
+
+        for kitty_id in kitty_ids {
+            if per_item_events {
+                Self::do_transfer(from.clone(), to.clone(), kitty_id, TransferReason::Direct)?;
+            } else {
+                Self::move_kitty(&from, &to, &kitty_id)?;
+            }
+        }
 
-        // Emite o evento de transferência.
-        Self::deposit_event(Event::<T>::Transferred { from, to, kitty_id });
+        Self::deposit_event(Event::<T>::BatchTransferred { from, to, count });
         Ok(())
     }
 
-    /// Define o preço de venda de um Kitty.
+    /// Define o preço de venda de um Kitty, retornando se o preço mudou.
+    ///
+    /// Repetir o preço atual não altera o armazenamento nem emite `PriceSet`, a menos que haja
+    /// um leilão em degraus, que é encerrado.
     pub fn do_set_price(
         caller: T::AccountId,
-        kitty_id: [u8; 32],
+        kitty_id: DnaOf<T>,
         new_price: Option<BalanceOf<T>>,
-    ) -> DispatchResult {
-        let mut kitty = Kitties::<T>::get(kitty_id).ok_or(Error::<T>::NoKitty)?;
-        ensure!(kitty.owner == caller, Error::<T>::NotOwner);
-        kitty.price = new_price;
+    ) -> Result<bool, DispatchError> {
+        let changed = Self::try_mutate_owned(&kitty_id, &caller, |kitty| {
+            // Um anúncio por zero deixaria qualquer um levar o Kitty com `max_price = 0`.
+            ensure!(new_price.map_or(true, |price| !price.is_zero()), Error::<T>::ZeroPrice);
+            if kitty.price == new_price {
+                // Repetir o preço ainda encerra um leilão em degraus, voltando ao preço fixo.
+                return Ok(StepAuctions::<T>::take(&kitty_id).is_some());
+            }
+            // Tirar do mercado é sempre permitido; anunciar ou trocar o preço respeita a espera.
+            if new_price.is_some() {
+                let now = frame_system::Pallet::<T>::block_number();
+                if let Some(last) = LastPriceUpdate::<T>::get(&kitty_id) {
+                    let next = last.saturating_add(T::PriceUpdateCooldown::get());
+                    ensure!(now >= next, Error::<T>::PriceUpdateTooSoon);
+                }
+                LastPriceUpdate::<T>::insert(&kitty_id, now);
+            }
+            StepAuctions::<T>::remove(&kitty_id);
 
-        // Atualiza o armazenamento.
-        Kitties::<T>::insert(kitty_id, kitty);
+            // Só a entrada e a saída do mercado mudam a contagem; trocar o preço não.
+            match (kitty.price.is_some(), new_price.is_some()) {
+                (false, true) => {
+                    let count = ListingCount::<T>::get(&caller).unwrap_or(0);
+                    let limit = T::MaxListingsPerAccount::get();
+                    ensure!(count < limit, Error::<T>::TooManyListings);
+                    ListingCount::<T>::insert(&caller, count + 1);
+                    ListedCount::<T>::mutate(|count| *count = count.saturating_add(1));
+                },
+                (true, false) => Self::release_listing(&caller),
+                _ => {},
+            }
+            kitty.price = new_price;
+            Ok(true)
+        })?;
+        if !changed {
+            return Ok(false);
+        }
 
         // Emite o evento de atualização de preço.
         Self::deposit_event(Event::<T>::PriceSet {
@@ -91,31 +937,834 @@ impl<T: Config> Pallet<T> {
             kitty_id,
             new_price,
         });
+        Ok(true)
+    }
+
+    /// Anuncia um Kitty do chamador em leilão em degraus.
+    pub fn do_start_step_auction(
+        caller: T::AccountId,
+        kitty_id: DnaOf<T>,
+        start_price: BalanceOf<T>,
+        step_size: Permill,
+        step_interval: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        ensure!(!step_interval.is_zero(), Error::<T>::ZeroStepInterval);
+        Self::do_set_price(caller, kitty_id.clone(), Some(start_price))?;
+
+        let started_at = frame_system::Pallet::<T>::block_number();
+        StepAuctions::<T>::insert(
+            &kitty_id,
+            StepAuction { start_price, step_size, step_interval, started_at },
+        );
+
+        Self::deposit_event(Event::<T>::StepAuctionStarted {
+            kitty_id,
+            start_price,
+            step_size,
+            step_interval,
+        });
         Ok(())
     }
 
+    /// Preço atual de um Kitty em leilão em degraus, ou `None` se ele não está em leilão.
+    ///
+    /// Só degraus completos contam: o preço muda exatamente quando `step_interval` blocos se
+    /// passam, e nunca cai abaixo de uma unidade.
+    pub fn current_step_price(kitty_id: &DnaOf<T>) -> Option<BalanceOf<T>> {
+        let auction = StepAuctions::<T>::get(kitty_id)?;
+        let now = frame_system::Pallet::<T>::block_number();
+        let elapsed = now.saturating_sub(auction.started_at);
+        let steps: u32 = (elapsed / auction.step_interval).unique_saturated_into();
+        let factor = auction.step_size.left_from_one().saturating_pow(steps as usize);
+        Some(factor.mul_floor(auction.start_price).max(One::one()))
+    }
+
+    /// Preço pelo qual o Kitty está anunciado agora, considerando um leilão em degraus.
+    fn listed_price(kitty_id: &DnaOf<T>, kitty: &Kitty<T>) -> Option<BalanceOf<T>> {
+        let price = kitty.price?;
+        Some(Self::current_step_price(kitty_id).unwrap_or(price))
+    }
+
     /// Realiza a compra de um Kitty por outro usuário.
+    ///
+    /// `max_price` é apenas o limite aceito pelo comprador: ele sempre paga exatamente o preço
+    /// anunciado, e nenhum excedente é cobrado. Enquanto houver uma reserva ativa, apenas o
+    /// comprador reservado pode comprar, pelo preço da reserva. Se o dono não for mais
+    /// `expected_seller`, nada é pago.
+    ///
+    /// As ofertas em aberto pelo Kitty são devolvidas, para que nenhuma seja aceita depois da
+    /// venda. Retorna quantas ofertas foram devolvidas.
     pub fn do_buy_kitty(
         buyer: T::AccountId,
-        kitty_id: [u8; 32],
-        price: BalanceOf<T>,
-    ) -> DispatchResult {
-        let kitty = Kitties::<T>::get(kitty_id).ok_or(Error::<T>::NoKitty)?;
-        let real_price = kitty.price.ok_or(Error::<T>::NotForSale)?;
-        ensure!(price >= real_price, Error::<T>::MaxPriceTooLow);
+        kitty_id: DnaOf<T>,
+        expected_seller: T::AccountId,
+        max_price: BalanceOf<T>,
+        keep_alive: bool,
+    ) -> Result<u32, DispatchError> {
+        let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+        ensure!(kitty.owner == expected_seller, Error::<T>::SellerChanged);
+        // Rejeita a compra do próprio Kitty antes de mover qualquer valor.
+        ensure!(kitty.owner != buyer, Error::<T>::TransferToSelf);
+        ensure!(!PendingBurns::<T>::contains_key(&kitty_id), Error::<T>::BurnPending);
+        let now = frame_system::Pallet::<T>::block_number();
+        let real_price = match Reservations::<T>::get(&kitty_id) {
+            Some(reservation) if reservation.expiry > now => {
+                ensure!(reservation.buyer == buyer, Error::<T>::ReservedForAnother);
+                reservation.price
+            },
+            _ => match PrivateSales::<T>::get(&kitty_id) {
+                Some(sale) => {
+                    ensure!(sale.buyer == buyer, Error::<T>::NotForSale);
+                    sale.price
+                },
+                None => Self::listed_price(&kitty_id, &kitty).ok_or(Error::<T>::NotForSale)?,
+            },
+        };
+        ensure!(max_price >= real_price, Error::<T>::MaxPriceTooLow);
 
-        // Transfere o valor da compra.
-        T::NativeBalance::transfer(&buyer, &kitty.owner, real_price, Preservation::Preserve)?;
+        // Distingue a falta de saldo da compra que só falharia por manter o comprador vivo.
+        let reducible = |preservation| Self::spendable_balance(&buyer, preservation);
+        if keep_alive && reducible(Preservation::Preserve) < real_price {
+            ensure!(reducible(Preservation::Expendable) < real_price, Error::<T>::WouldReapBuyer);
+        }
+
+        // Transfere exatamente o preço anunciado.
+        let preservation =
+            if keep_alive { Preservation::Preserve } else { Preservation::Expendable };
+        Self::transfer_funds(&buyer, &kitty.owner, real_price, preservation)?;
 
         // Transfere a posse do Kitty.
-        Self::do_transfer(kitty.owner, buyer.clone(), kitty_id)?;
+        let seller = kitty.owner;
+        Self::do_transfer(seller.clone(), buyer.clone(), kitty_id.clone(), TransferReason::Sale)?;
+        Self::record_sale(&kitty_id, real_price);
+        let refunded = Self::refund_all_offers(&kitty_id, T::MaxOffersPerKitty::get())?;
 
-        // Emite o evento de venda.
+        // Emite o evento de venda e avisa quem estiver interessado.
+        T::OnSale::on_sale(&buyer, &seller, &kitty_id, real_price);
         Self::deposit_event(Event::<T>::Sold {
             buyer,
+            seller,
             kitty_id,
             price: real_price,
         });
+        Ok(refunded)
+    }
+
+    /// Aplica `ListingDecay` ao preço dos Kitties à venda.
+    ///
+    /// Visita no máximo `MaxDecaysPerBlock` Kitties por bloco, continuando de onde o bloco
+    /// anterior parou, para que o custo não cresça com o número de Kitties.
+    pub fn decay_listings() -> Weight {
+        let decay = T::ListingDecay::get();
+        if decay.is_zero() {
+            return Weight::zero();
+        }
+
+        let mut iter = match DecayCursor::<T>::get() {
+            Some(last) => Kitties::<T>::iter_from(Kitties::<T>::hashed_key_for(&last)),
+            None => Kitties::<T>::iter(),
+        };
+        let limit = T::MaxDecaysPerBlock::get();
+        let mut visited = 0u32;
+        let mut updated = 0u32;
+        let mut last = None;
+
+        while visited < limit {
+            let Some((kitty_id, mut kitty)) = iter.next() else { break };
+            visited += 1;
+            last = Some(kitty_id.clone());
+
+            let Some(price) = kitty.price else { continue };
+            if StepAuctions::<T>::contains_key(&kitty_id) {
+                continue;
+            }
+            // Como em `current_step_price`, o preço nunca chega a zero, que `set_price` recusa.
+            let new_price = price.saturating_sub(decay.mul_floor(price)).max(One::one());
+            if new_price == price {
+                continue;
+            }
+
+            kitty.price = Some(new_price);
+            let owner = kitty.owner.clone();
+            Kitties::<T>::insert(&kitty_id, kitty);
+            updated += 1;
+
+            Self::deposit_event(Event::<T>::PriceSet {
+                owner,
+                kitty_id,
+                new_price: Some(new_price),
+            });
+        }
+
+        // Recomeça do início quando a varredura chega ao fim.
+        match last {
+            Some(last) if visited == limit && iter.next().is_some() => DecayCursor::<T>::put(last),
+            _ => DecayCursor::<T>::kill(),
+        }
+
+        T::DbWeight::get().reads_writes(u64::from(visited) + 2, u64::from(updated) + 1)
+    }
+
+    /// Devolve as ofertas expiradas que couberem em `remaining_weight`, continuando de onde o
+    /// bloco anterior parou.
+    ///
+    /// Cada oferta visitada é cobrada por `WeightInfo::cleanup_expired_offer`, o custo de uma
+    /// devolução, mesmo que ainda não tenha expirado; assim o peso usado nunca passa do
+    /// orçamento.
+    pub fn cleanup_expired_offers(remaining_weight: Weight) -> Weight {
+        // Leitura e escrita do cursor.
+        let base = T::DbWeight::get().reads_writes(1, 1);
+        let per_offer = T::WeightInfo::cleanup_expired_offer();
+        let Some(budget) = remaining_weight.checked_sub(&base) else { return Weight::zero() };
+        let limit = budget.checked_div_per_component(&per_offer).unwrap_or(0);
+        if limit == 0 {
+            return Weight::zero();
+        }
+
+        let mut iter = match OfferCleanupCursor::<T>::get() {
+            Some((kitty_id, bidder)) => {
+                Offers::<T>::iter_from(Offers::<T>::hashed_key_for(kitty_id, bidder))
+            },
+            None => Offers::<T>::iter(),
+        };
+        let now = frame_system::Pallet::<T>::block_number();
+        let mut visited = 0u64;
+        let mut last = None;
+
+        while visited < limit {
+            let Some((kitty_id, bidder, offer)) = iter.next() else { break };
+            visited += 1;
+            if offer.expiry <= now && Self::refund_offer(&kitty_id, &bidder).is_ok() {
+                Self::deposit_event(Event::<T>::ExpiredOfferRefunded {
+                    kitty_id: kitty_id.clone(),
+                    bidder: bidder.clone(),
+                    amount: offer.amount,
+                });
+            }
+            last = Some((kitty_id, bidder));
+        }
+
+        // Recomeça do início quando a varredura chega ao fim.
+        match last {
+            Some(last) if visited == limit && iter.next().is_some() => {
+                OfferCleanupCursor::<T>::put(last)
+            },
+            _ => OfferCleanupCursor::<T>::kill(),
+        }
+
+        base.saturating_add(per_offer.saturating_mul(visited))
+    }
+
+    /// Reserva um Kitty para um comprador específico até o bloco `expiry`.
+    pub fn do_reserve_for(
+        caller: T::AccountId,
+        kitty_id: DnaOf<T>,
+        buyer: T::AccountId,
+        price: BalanceOf<T>,
+        expiry: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        ensure!(expiry > frame_system::Pallet::<T>::block_number(), Error::<T>::ExpiryInPast);
+
+        // Atualiza o armazenamento.
+        Reservations::<T>::insert(&kitty_id, Reservation { buyer: buyer.clone(), price, expiry });
+
+        // Emite o evento de reserva.
+        Self::deposit_event(Event::<T>::Reserved { kitty_id, buyer, price, expiry });
+        Ok(())
+    }
+
+    /// Cancela a reserva de um Kitty.
+    pub fn do_cancel_reservation(caller: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        ensure!(Reservations::<T>::contains_key(&kitty_id), Error::<T>::NoReservation);
+
+        // Atualiza o armazenamento.
+        Reservations::<T>::remove(&kitty_id);
+
+        // Emite o evento de cancelamento.
+        Self::deposit_event(Event::<T>::ReservationCancelled { kitty_id });
+        Ok(())
+    }
+
+    /// Alimenta um Kitty do chamador, respeitando `FeedCooldown` e o teto `MAX_LEVEL`.
+    pub fn do_feed(caller: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        let mut kitty = Self::ensure_owner(&kitty_id, &caller)?;
+
+        // Nenhuma chamada roda no bloco zero, então `last_fed` zero significa nunca alimentado.
+        let now = frame_system::Pallet::<T>::block_number();
+        let next_feed = kitty.last_fed.saturating_add(T::FeedCooldown::get());
+        ensure!(kitty.last_fed.is_zero() || now >= next_feed, Error::<T>::FeedTooSoon);
+
+        kitty.level = kitty.level.saturating_add(1).min(MAX_LEVEL);
+        kitty.last_fed = now;
+        let new_level = kitty.level;
+        Kitties::<T>::insert(&kitty_id, kitty);
+
+        Self::deposit_event(Event::<T>::Fed { kitty_id, new_level });
+        Ok(())
+    }
+
+    /// Cobra `AccelerateFee` do dono e libera o Kitty para cruzar já no bloco atual.
+    pub fn do_accelerate_cooldown(caller: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        let mut kitty = Self::ensure_owner(&kitty_id, &caller)?;
+        let now = frame_system::Pallet::<T>::block_number();
+        ensure!(kitty.cooldown_until > now, Error::<T>::NotOnCooldown);
+
+        let fee = T::AccelerateFee::get();
+        if !fee.is_zero() {
+            let destination = T::FeeResolver::fee_destination();
+            Self::transfer_funds(&caller, &destination, fee, Preservation::Preserve)?;
+        }
+
+        kitty.cooldown_until = now;
+        Kitties::<T>::insert(&kitty_id, kitty);
+
+        Self::deposit_event(Event::<T>::CooldownAccelerated { owner: caller, kitty_id, fee });
+        Ok(())
+    }
+
+    /// Equipa `item_id`, que precisa pertencer ao chamador e não estar em outro Kitty, num
+    /// Kitty dele.
+    pub fn do_equip(
+        caller: T::AccountId,
+        kitty_id: DnaOf<T>,
+        item_id: T::ItemId,
+    ) -> DispatchResult {
+        let mut kitty = Self::ensure_owner(&kitty_id, &caller)?;
+        ensure!(
+            T::ItemsInspect::owner_of(&item_id).as_ref() == Some(&caller),
+            Error::<T>::NotItemOwner
+        );
+        ensure!(
+            EquippedItems::<T>::get(item_id).map_or(true, |equipped_on| equipped_on == kitty_id),
+            Error::<T>::ItemAlreadyEquipped
+        );
+
+        if let Some(previous) = kitty.equipped.replace(item_id) {
+            EquippedItems::<T>::remove(previous);
+        }
+        EquippedItems::<T>::insert(item_id, &kitty_id);
+        Kitties::<T>::insert(&kitty_id, kitty);
+
+        Self::deposit_event(Event::<T>::Equipped { owner: caller, kitty_id, item_id });
+        Ok(())
+    }
+
+    /// Remove o item equipado num Kitty do chamador.
+    pub fn do_unequip(caller: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        let mut kitty = Self::ensure_owner(&kitty_id, &caller)?;
+        let item_id = kitty.equipped.take().ok_or(Error::<T>::NothingEquipped)?;
+        Kitties::<T>::insert(&kitty_id, kitty);
+
+        Self::release_item(&caller, &kitty_id, item_id);
+        Ok(())
+    }
+
+    /// Libera `item_id`, já tirado do Kitty, para ser equipado de novo por `owner`.
+    fn release_item(owner: &T::AccountId, kitty_id: &DnaOf<T>, item_id: T::ItemId) {
+        EquippedItems::<T>::remove(item_id);
+        Self::deposit_event(Event::<T>::Unequipped {
+            owner: owner.clone(),
+            kitty_id: kitty_id.clone(),
+            item_id,
+        });
+    }
+
+    /// Define ou remove a conta autorizada a transferir um Kitty do chamador.
+    pub fn do_approve(
+        caller: T::AccountId,
+        kitty_id: DnaOf<T>,
+        spender: Option<T::AccountId>,
+    ) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+
+        // Atualiza o armazenamento.
+        match &spender {
+            Some(spender) => Approvals::<T>::insert(&kitty_id, spender),
+            None => Approvals::<T>::remove(&kitty_id),
+        }
+
+        // Emite o evento de autorização.
+        Self::deposit_event(Event::<T>::Approved { owner: caller, kitty_id, spender });
+        Ok(())
+    }
+
+    /// Transfere um Kitty em nome do dono, usando a autorização do chamador.
+    pub fn do_transfer_from(
+        spender: T::AccountId,
+        kitty_id: DnaOf<T>,
+        to: T::AccountId,
+    ) -> DispatchResult {
+        ensure!(Approvals::<T>::get(&kitty_id) == Some(spender), Error::<T>::NotApproved);
+        let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+        Self::do_transfer(kitty.owner, to, kitty_id, TransferReason::Direct)
+    }
+
+    /// Conta do pallet, que guarda os fundos das ofertas em aberto.
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Subconta do pallet onde `PalletFeeAccount` acumula as taxas, separada dos fundos das
+    /// ofertas em `account_id`.
+    pub fn fee_account() -> T::AccountId {
+        T::PalletId::get().into_sub_account_truncating(*b"fees")
+    }
+
+    /// Transfere taxas acumuladas em `fee_account` para `to`.
+    pub fn do_withdraw_fees(to: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+        let from = Self::fee_account();
+        Self::transfer_funds(&from, &to, amount, Preservation::Expendable)?;
+        Self::deposit_event(Event::<T>::FeesWithdrawn { to, amount });
+        Ok(())
+    }
+
+    /// Registra uma oferta de `bidder`, movendo o valor para a conta do pallet.
+    pub fn do_make_offer(
+        bidder: T::AccountId,
+        kitty_id: DnaOf<T>,
+        amount: BalanceOf<T>,
+        expiry: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        let kitty = Kitties::<T>::get(&kitty_id).ok_or(Error::<T>::NoKitty)?;
+        ensure!(kitty.owner != bidder, Error::<T>::TransferToSelf);
+        ensure!(!amount.is_zero(), Error::<T>::ZeroPrice);
+        let now = frame_system::Pallet::<T>::block_number();
+        ensure!(expiry > now, Error::<T>::ExpiryInPast);
+        ensure!(!Offers::<T>::contains_key(&kitty_id, &bidder), Error::<T>::DuplicateOffer);
+        let offers = OfferCount::<T>::get(&kitty_id);
+        ensure!(offers < T::MaxOffersPerKitty::get(), Error::<T>::TooManyOffers);
+
+        // Guarda o valor ofertado na conta do pallet.
+        Self::transfer_funds(&bidder, &Self::account_id(), amount, Preservation::Preserve)?;
+        Offers::<T>::insert(&kitty_id, &bidder, Offer { amount, expiry });
+        OfferCount::<T>::insert(&kitty_id, offers + 1);
+        OfferedTotal::<T>::mutate(&bidder, |total| *total = total.saturating_add(amount));
+
+        Self::deposit_event(Event::<T>::OfferMade { kitty_id, bidder, amount, expiry });
+        Ok(())
+    }
+
+    /// Soma dos valores guardados nas ofertas em aberto de `who`, expiradas ou não.
+    pub fn total_reserved_offers(who: &T::AccountId) -> BalanceOf<T> {
+        OfferedTotal::<T>::get(who)
+    }
+
+    /// Remove a oferta de `bidder`, descontando-a de `OfferCount` e `OfferedTotal`.
+    fn take_offer(kitty_id: &DnaOf<T>, bidder: &T::AccountId) -> Result<Offer<T>, DispatchError> {
+        let offer = Offers::<T>::take(kitty_id, bidder).ok_or(Error::<T>::NoOffer)?;
+        OfferCount::<T>::mutate_exists(kitty_id, |count| {
+            *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+        });
+        OfferedTotal::<T>::mutate_exists(bidder, |total| {
+            *total = total.map(|sum| sum.saturating_sub(offer.amount)).filter(|sum| !sum.is_zero());
+        });
+        Ok(offer)
+    }
+
+    /// Remove a oferta de `bidder` e devolve o valor a ele.
+    fn refund_offer(kitty_id: &DnaOf<T>, bidder: &T::AccountId) -> DispatchResult {
+        let offer = Self::take_offer(kitty_id, bidder)?;
+        Self::transfer_funds(&Self::account_id(), bidder, offer.amount, Preservation::Expendable)?;
+        Ok(())
+    }
+
+    /// Devolve até `limit` ofertas em aberto pelo Kitty, retornando quantas foram devolvidas.
+    ///
+    /// Ofertas além desse limite continuam guardadas e podem ser retiradas com
+    /// `withdraw_offer`.
+    fn refund_all_offers(kitty_id: &DnaOf<T>, limit: u32) -> Result<u32, DispatchError> {
+        let bidders: Vec<_> = Offers::<T>::iter_key_prefix(kitty_id).take(limit as usize).collect();
+        for bidder in &bidders {
+            Self::refund_offer(kitty_id, bidder)?;
+            Self::deposit_event(Event::<T>::OfferRejected {
+                kitty_id: kitty_id.clone(),
+                bidder: bidder.clone(),
+            });
+        }
+        Ok(bidders.len() as u32)
+    }
+
+    /// Devolve e remove todas as ofertas por um Kitty do chamador, retornando quantas foram
+    /// devolvidas. `MaxOffersPerKitty` limita quantas podem existir.
+    pub fn do_clear_offers(caller: T::AccountId, kitty_id: DnaOf<T>) -> Result<u32, DispatchError> {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        Self::refund_all_offers(&kitty_id, T::MaxOffersPerKitty::get())
+    }
+
+    /// Retira a oferta do próprio comprador.
+    pub fn do_withdraw_offer(bidder: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        Self::refund_offer(&kitty_id, &bidder)?;
+        Self::deposit_event(Event::<T>::OfferWithdrawn { kitty_id, bidder });
+        Ok(())
+    }
+
+    /// Rejeita a oferta de `bidder` por um Kitty do chamador.
+    pub fn do_reject_offer(
+        caller: T::AccountId,
+        kitty_id: DnaOf<T>,
+        bidder: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+
+        Self::refund_offer(&kitty_id, &bidder)?;
+        Self::deposit_event(Event::<T>::OfferRejected { kitty_id, bidder });
+        Ok(())
+    }
+
+    /// Vende um Kitty do chamador para `bidder` pelo valor da oferta.
+    pub fn do_accept_offer(
+        caller: T::AccountId,
+        kitty_id: DnaOf<T>,
+        bidder: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        let offer = Self::take_offer(&kitty_id, &bidder)?;
+        let now = frame_system::Pallet::<T>::block_number();
+        ensure!(offer.expiry > now, Error::<T>::OfferExpired);
+
+        // Paga o vendedor com o valor guardado e transfere a posse.
+        Self::transfer_funds(&Self::account_id(), &caller, offer.amount, Preservation::Expendable)?;
+        Self::do_transfer(caller.clone(), bidder.clone(), kitty_id.clone(), TransferReason::Sale)?;
+        Self::record_sale(&kitty_id, offer.amount);
+
+        T::OnSale::on_sale(&bidder, &caller, &kitty_id, offer.amount);
+        Self::deposit_event(Event::<T>::OfferAccepted { kitty_id, bidder, amount: offer.amount });
+        Ok(())
+    }
+
+    /// Aceita a maior oferta válida de cada Kitty e rejeita as outras examinadas, retornando
+    /// quantas ofertas foram examinadas.
+    pub fn do_accept_best_offers(
+        caller: T::AccountId,
+        kitty_ids: BoundedVec<DnaOf<T>, T::MaxBatchSize>,
+    ) -> Result<u32, DispatchError> {
+        let now = frame_system::Pallet::<T>::block_number();
+        let mut scanned = 0u32;
+
+        for kitty_id in kitty_ids {
+            Self::ensure_owner(&kitty_id, &caller)?;
+            let offers: Vec<_> = Offers::<T>::iter_prefix(&kitty_id)
+                .take(T::MaxOffersPerKitty::get() as usize)
+                .collect();
+            scanned = scanned.saturating_add(offers.len() as u32);
+
+            let Some(best) = offers
+                .iter()
+                .filter(|(_, offer)| offer.expiry > now)
+                .max_by_key(|(_, offer)| offer.amount)
+                .map(|(bidder, _)| bidder.clone())
+            else {
+                continue;
+            };
+
+            for (bidder, _) in offers.into_iter().filter(|(bidder, _)| *bidder != best) {
+                Self::refund_offer(&kitty_id, &bidder)?;
+                Self::deposit_event(Event::<T>::OfferRejected {
+                    kitty_id: kitty_id.clone(),
+                    bidder,
+                });
+            }
+            Self::do_accept_offer(caller.clone(), kitty_id, best)?;
+        }
+        Ok(scanned)
+    }
+
+    /// Cria uma venda privada de um Kitty do chamador para `buyer`.
+    pub fn do_private_sale(
+        caller: T::AccountId,
+        kitty_id: DnaOf<T>,
+        buyer: T::AccountId,
+        price: BalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        ensure!(buyer != caller, Error::<T>::TransferToSelf);
+        ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+
+        // Atualiza o armazenamento.
+        PrivateSales::<T>::insert(&kitty_id, PrivateSale { buyer: buyer.clone(), price });
+
+        // Emite o evento de venda privada.
+        Self::deposit_event(Event::<T>::PrivateSaleCreated { kitty_id, buyer, price });
+        Ok(())
+    }
+
+    /// Cancela a venda privada de um Kitty do chamador.
+    pub fn do_cancel_private_sale(caller: T::AccountId, kitty_id: DnaOf<T>) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        ensure!(PrivateSales::<T>::take(&kitty_id).is_some(), Error::<T>::NoPrivateSale);
+
+        // Emite o evento de cancelamento.
+        Self::deposit_event(Event::<T>::PrivateSaleCancelled { kitty_id });
+        Ok(())
+    }
+
+    /// Verifica se `who` conseguiria cruzar os dois Kitties agora, com as mesmas regras de
+    /// `breed_kitty`, sem alterar o armazenamento.
+    pub fn can_breed(
+        parent_1: &DnaOf<T>,
+        parent_2: &DnaOf<T>,
+        who: &T::AccountId,
+    ) -> Result<(), BreedError> {
+        let kitty_1 = Kitties::<T>::get(parent_1).ok_or(BreedError::NoKitty)?;
+        let kitty_2 = Kitties::<T>::get(parent_2).ok_or(BreedError::NoKitty)?;
+        let owner = kitty_1.owner;
+        if kitty_2.owner != owner {
+            return Err(BreedError::DifferentOwners);
+        }
+        let approved = |kitty_id: &DnaOf<T>| Approvals::<T>::get(kitty_id).as_ref() == Some(who);
+        if *who != owner && !(approved(parent_1) && approved(parent_2)) {
+            return Err(BreedError::NotAuthorized);
+        }
+        if parent_1 == parent_2 {
+            return Err(BreedError::SameParents);
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        if kitty_1.cooldown_until > now || kitty_2.cooldown_until > now {
+            return Err(BreedError::OnCooldown);
+        }
+        let generation = Generations::<T>::get(parent_1).max(Generations::<T>::get(parent_2));
+        if generation >= T::MaxGeneration::get() {
+            return Err(BreedError::MaxGenerationReached);
+        }
+        if CountForKitties::<T>::get() >= T::MaxSupply::get() {
+            return Err(BreedError::TooManyKitties);
+        }
+        let (used, limit) = Self::owned_capacity(&owner);
+        if used >= limit {
+            return Err(BreedError::TooManyOwned);
+        }
+        Ok(())
+    }
+
+    /// Verifica se `buyer` conseguiria comprar o Kitty agora, retornando o preço que pagaria.
+    pub fn can_buy(buyer: &T::AccountId, kitty_id: &DnaOf<T>) -> Result<BalanceOf<T>, BuyError> {
+        let kitty = Kitties::<T>::get(kitty_id).ok_or(BuyError::NoKitty)?;
+        if kitty.owner == *buyer {
+            return Err(BuyError::AlreadyOwner);
+        }
+        if PendingBurns::<T>::contains_key(kitty_id) {
+            return Err(BuyError::BurnPending);
+        }
+        if Self::is_transfer_locked(&kitty) {
+            return Err(BuyError::TransferLocked);
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let price = match Reservations::<T>::get(kitty_id) {
+            Some(reservation) if reservation.expiry > now => {
+                if reservation.buyer != *buyer {
+                    return Err(BuyError::ReservedForAnother);
+                }
+                reservation.price
+            },
+            _ => match PrivateSales::<T>::get(kitty_id) {
+                Some(sale) if sale.buyer != *buyer => return Err(BuyError::NotForSale),
+                Some(sale) => sale.price,
+                None => Self::listed_price(kitty_id, &kitty).ok_or(BuyError::NotForSale)?,
+            },
+        };
+
+        let preservation = T::BuyPreservation::get();
+        let available = Self::spendable_balance(buyer, preservation);
+        if available < price {
+            return Err(BuyError::InsufficientFunds);
+        }
+        Ok(price)
+    }
+
+    /// Retorna as contas que têm o Kitty em `OwnedKitties`; deveria ser exatamente uma.
+    ///
+    /// Varre todo o `OwnedKitties`, então serve apenas para diagnóstico e testes, nunca para
+    /// chamadas.
+    pub fn accounts_holding(kitty_id: &DnaOf<T>) -> Vec<T::AccountId> {
+        OwnedKitties::<T>::iter_keys()
+            .filter(|(_, owned)| owned == kitty_id)
+            .map(|(who, _)| who)
+            .collect()
+    }
+
+    /// Verifica a consistência entre `CountForKitties`, `Kitties`, `OwnedKitties` e
+    /// `OwnedCount`, e entre `Offers`, `OfferCount` e `OfferedTotal`.
+    #[cfg(any(feature = "try-runtime", test))]
+    pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+        let count = Kitties::<T>::iter_keys().count() as u32;
+        ensure!(CountForKitties::<T>::get() == count, "CountForKitties difere de Kitties");
+
+        let mut seen = alloc::collections::BTreeSet::new();
+        // Kitties possuídos e anunciados por dono.
+        let mut per_owner = alloc::collections::BTreeMap::<T::AccountId, (u32, u32)>::new();
+        let mut equipped = 0u32;
+        for (owner, kitty_id) in OwnedKitties::<T>::iter_keys() {
+            let kitty = Kitties::<T>::get(&kitty_id).ok_or("Kitty possuído não existe")?;
+            ensure!(kitty.owner == owner, "Kitty listado por quem não é o dono");
+            ensure!(seen.insert(kitty_id), "Kitty listado por dois donos");
+            let (owned, listed) = per_owner.entry(owner).or_default();
+            *owned += 1;
+            *listed += u32::from(kitty.price.is_some());
+            equipped += u32::from(kitty.equipped.is_some());
+        }
+
+        let mut total_listed = 0u32;
+        for (owner, (owned, listed)) in &per_owner {
+            ensure!(OwnedCount::<T>::get(owner) == *owned, "OwnedCount difere da posse do dono");
+            let counted = ListingCount::<T>::get(owner).unwrap_or(0);
+            ensure!(counted == *listed, "ListingCount difere dos anúncios do dono");
+            total_listed += listed;
+        }
+        let owners = per_owner.len() as u32;
+        ensure!(OwnedCount::<T>::iter_keys().count() as u32 == owners, "OwnedCount sem posse");
+        ensure!(ListedCount::<T>::get() == total_listed, "ListedCount difere dos anúncios");
+        ensure!(OwnerCount::<T>::get() == owners, "OwnerCount difere das listas de posse");
+        ensure!(seen.len() as u32 == count, "Kitty ausente das listas de posse");
+
+        let mut indexed = 0u32;
+        for (item_id, kitty_id) in EquippedItems::<T>::iter() {
+            let kitty = Kitties::<T>::get(&kitty_id).ok_or("Item equipado em Kitty inexistente")?;
+            ensure!(kitty.equipped == Some(item_id), "EquippedItems difere do item do Kitty");
+            indexed += 1;
+        }
+        ensure!(indexed == equipped, "Item equipado ausente de EquippedItems");
+
+        let mut offered = alloc::collections::BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+        let mut per_kitty = alloc::collections::BTreeMap::<DnaOf<T>, u32>::new();
+        for (kitty_id, bidder, offer) in Offers::<T>::iter() {
+            let total = offered.entry(bidder).or_insert_with(Zero::zero);
+            *total = total.saturating_add(offer.amount);
+            *per_kitty.entry(kitty_id).or_default() += 1;
+        }
+        for (kitty_id, count) in OfferCount::<T>::iter() {
+            ensure!(per_kitty.remove(&kitty_id) == Some(count), "OfferCount difere das ofertas");
+        }
+        ensure!(per_kitty.is_empty(), "Oferta ausente de OfferCount");
+        for (bidder, total) in OfferedTotal::<T>::iter() {
+            ensure!(offered.remove(&bidder) == Some(total), "OfferedTotal difere das ofertas");
+        }
+        ensure!(offered.is_empty(), "Oferta ausente de OfferedTotal");
+
+        Ok(())
+    }
+
+    /// Cruza dois Kitties do mesmo dono, a pedido do dono ou de uma conta autorizada nos dois.
+    pub fn do_breed_kitty(
+        caller: T::AccountId,
+        parent_1: DnaOf<T>,
+        parent_2: DnaOf<T>,
+    ) -> DispatchResult {
+        let kitty_1 = Kitties::<T>::get(&parent_1).ok_or(Error::<T>::NoKitty)?;
+        let kitty_2 = Kitties::<T>::get(&parent_2).ok_or(Error::<T>::NoKitty)?;
+        let owner = kitty_1.owner.clone();
+        ensure!(kitty_2.owner == owner, Error::<T>::NotOwner);
+
+        let approved =
+            |kitty_id: &DnaOf<T>| Approvals::<T>::get(kitty_id).as_ref() == Some(&caller);
+        ensure!(
+            caller == owner || (approved(&parent_1) && approved(&parent_2)),
+            Error::<T>::NotAuthorizedToBreed
+        );
+
+        // O filho vai para o dono, mesmo quando quem cruza é uma conta autorizada.
+        Self::breed(owner, kitty_1, kitty_2)
+    }
+
+    /// Oferece (ou retira, com `None`) um Kitty como reprodutor.
+    pub fn do_offer_as_sire(
+        caller: T::AccountId,
+        kitty_id: DnaOf<T>,
+        fee: Option<BalanceOf<T>>,
+    ) -> DispatchResult {
+        Self::ensure_owner(&kitty_id, &caller)?;
+        let min_fee = T::MinSireFee::get();
+        ensure!(fee.map_or(true, |fee| fee >= min_fee), Error::<T>::SireFeeTooLow);
+
+        // Atualiza o armazenamento.
+        match fee {
+            Some(fee) => SireListings::<T>::insert(&kitty_id, fee),
+            None => SireListings::<T>::remove(&kitty_id),
+        }
+
+        // Emite o evento de atualização da taxa.
+        Self::deposit_event(Event::<T>::SireFeeSet {
+            owner: caller,
+            kitty_id,
+            fee,
+        });
+        Ok(())
+    }
+
+    /// Cruza um Kitty do chamador com um reprodutor ofertado por outro usuário.
+    pub fn do_breed_with_sire(
+        caller: T::AccountId,
+        my_kitty: DnaOf<T>,
+        sire_id: DnaOf<T>,
+    ) -> DispatchResult {
+        let kitty = Self::ensure_owner(&my_kitty, &caller)?;
+        let sire = Kitties::<T>::get(&sire_id).ok_or(Error::<T>::NoKitty)?;
+        let fee = SireListings::<T>::get(&sire_id).ok_or(Error::<T>::NotSire)?;
+
+        // Paga a taxa ao dono do reprodutor, que continua com ele.
+        if sire.owner != caller {
+            Self::transfer_funds(&caller, &sire.owner, fee, Preservation::Preserve)?;
+        }
+
+        Self::breed(caller, kitty, sire)
+    }
+
+    /// Queima dois Kitties do chamador e cria para ele um Kitty com o DNA combinado dos dois.
+    pub fn do_merge(caller: T::AccountId, kitty_a: DnaOf<T>, kitty_b: DnaOf<T>) -> DispatchResult {
+        ensure!(kitty_a != kitty_b, Error::<T>::SameParents);
+        Self::ensure_owner(&kitty_a, &caller)?;
+        Self::ensure_owner(&kitty_b, &caller)?;
+
+        // O resultado herda a maior geração, sem avançá-la, para que a fusão não a reinicie.
+        let generation = Generations::<T>::get(&kitty_a).max(Generations::<T>::get(&kitty_b));
+
+        // Queima antes de criar, liberando espaço na posse e na oferta máxima.
+        Self::do_burn_kitty(caller.clone(), kitty_a.clone())?;
+        Self::do_burn_kitty(caller.clone(), kitty_b.clone())?;
+        let created = Self::breed_dna(&caller, &kitty_a, &kitty_b);
+        Self::mint(caller, created.clone())?;
+        if generation > 0 {
+            Generations::<T>::insert(&created, generation);
+        }
+
+        Self::deposit_event(Event::<T>::Merged { burned: [kitty_a, kitty_b], created });
+        Ok(())
+    }
+
+    /// Gera o filho de dois Kitties para `owner` e aplica o período de espera nos pais.
+    fn breed(owner: T::AccountId, mut kitty_1: Kitty<T>, mut kitty_2: Kitty<T>) -> DispatchResult {
+        ensure!(kitty_1.dna != kitty_2.dna, Error::<T>::SameParents);
+
+        // Garante que nenhum dos pais está no período de espera.
+        let now = frame_system::Pallet::<T>::block_number();
+        ensure!(
+            kitty_1.cooldown_until <= now && kitty_2.cooldown_until <= now,
+            Error::<T>::KittyOnCooldown
+        );
+
+        let generation = Generations::<T>::get(&kitty_1.dna)
+            .max(Generations::<T>::get(&kitty_2.dna))
+            .saturating_add(1);
+        ensure!(generation <= T::MaxGeneration::get(), Error::<T>::MaxGenerationReached);
+
+        // Cria o filho.
+        let dna = Self::breed_dna(&owner, &kitty_1.dna, &kitty_2.dna);
+        Self::mint(owner.clone(), dna.clone())?;
+        Generations::<T>::insert(&dna, generation);
+
+        // Atualiza o período de espera dos pais.
+        let cooldown_until = now.saturating_add(T::BreedingCooldown::get());
+        kitty_1.cooldown_until = cooldown_until;
+        kitty_2.cooldown_until = cooldown_until;
+        let parents = [kitty_1.dna.clone(), kitty_2.dna.clone()];
+        Kitties::<T>::insert(&parents[0], kitty_1);
+        Kitties::<T>::insert(&parents[1], kitty_2);
+
+        // Emite o evento de cruzamento.
+        Self::deposit_event(Event::<T>::Bred { owner, kitty_id: dna, parents });
         Ok(())
     }
 }
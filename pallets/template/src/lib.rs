@@ -1,29 +1,57 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod balances;
+
+pub mod dna;
+pub use dna::*;
+
+pub mod hooks;
+pub use hooks::*;
+
+mod impls;
+
+pub mod migrations;
+
 #[cfg(test)]
 mod mock;
 
-mod weights;
-pub use weights::*;
+#[cfg(test)]
+mod tests;
 
-use frame_support::pallet_prelude::*;
-use frame_system::pallet_prelude::*;
-use sp_runtime::traits::Zero;
-use sp_runtime::traits::BlakeTwo256;
-use sp_runtime::traits::AtLeast32BitUnsigned;
+#[cfg(test)]
+mod property_tests;
 
+pub mod runtime_api;
+
+pub mod traits;
+pub use traits::*;
+
+pub mod weights;
+pub use weights::*;
 
+pub use pallet::*;
 
+use frame_support::pallet_prelude::*;
+use frame_support::traits::{fungible, tokens::Preservation};
+use frame_support::PalletId;
+use frame_system::pallet_prelude::*;
+use sp_runtime::Permill;
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
+    /// Versão atual do armazenamento do pallet.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     /// Estrutura principal do pallet.
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Configuração do pallet, definindo os tipos necessários.
@@ -32,24 +60,374 @@ pub mod pallet {
         /// Evento genérico para este pallet.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        /// Define o tipo de saldo nativo usado para transações de Kitties.
-        type NativeBalance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy + MaybeSerializeDeserialize;
+        /// Define a moeda nativa usada para transações de Kitties.
+        type NativeBalance: fungible::Inspect<Self::AccountId> + fungible::Mutate<Self::AccountId>;
 
         /// Define o limite máximo de Kitties que um usuário pode possuir.
         #[pallet::constant]
         type MaxKittiesOwned: Get<u32>;
+
+        /// Número de blocos que um Kitty precisa esperar entre dois cruzamentos.
+        #[pallet::constant]
+        type BreedingCooldown: Get<BlockNumberFor<Self>>;
+
+        /// Fração do preço que os Kitties à venda perdem a cada bloco, sem descer abaixo de 1.
+        /// Zero desativa o modo de depreciação.
+        #[pallet::constant]
+        type ListingDecay: Get<Permill>;
+
+        /// Número máximo de Kitties visitados por bloco ao aplicar a depreciação.
+        #[pallet::constant]
+        type MaxDecaysPerBlock: Get<u32>;
+
+        /// Número de bytes zero no início do DNA exigidos por `create_kitty_pow`.
+        #[pallet::constant]
+        type MintDifficulty: Get<u8>;
+
+        /// Chamado ao final de cada criação de Kitty.
+        type OnMint: OnNewKitty<Self::AccountId>;
+
+        /// Chamado ao final de cada venda de Kitty.
+        type OnSale: OnKittySold<Self::AccountId, BalanceOf<Self>>;
+
+        /// Número máximo de Kitties em uma operação em lote.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Se operações em lote emitem um evento por item ou apenas um evento de resumo.
+        #[pallet::constant]
+        type EmitPerItemEvents: Get<bool>;
+
+        /// Pesos das chamadas, gerados pelos benchmarks.
+        type WeightInfo: WeightInfo;
+
+        /// Tamanho máximo, em bytes, do nome de um Kitty.
+        #[pallet::constant]
+        type MaxNameLength: Get<u32>;
+
+        /// Política de caracteres aceitos nos nomes.
+        type NamePolicy: NameValidator;
+
+        /// Tamanho máximo, em bytes, do memo de `transfer_with_memo`.
+        #[pallet::constant]
+        type MaxMemo: Get<u32>;
+
+        /// Quantos Kitties as consultas que varrem `Kitties` podem visitar.
+        #[pallet::constant]
+        type MaxScan: Get<u32>;
+
+        /// Quantos blocos uma queima pedida com `request_burn` aguarda antes de poder ser
+        /// finalizada.
+        #[pallet::constant]
+        type BurnDelay: Get<BlockNumberFor<Self>>;
+
+        /// Quantos blocos um Kitty precisa esperar entre duas alimentações.
+        #[pallet::constant]
+        type FeedCooldown: Get<BlockNumberFor<Self>>;
+
+        /// Quantos Kitties uma conta pode ter anunciados ao mesmo tempo.
+        #[pallet::constant]
+        type MaxListingsPerAccount: Get<u32>;
+
+        /// Identificador da conta do pallet, que guarda os fundos das ofertas em aberto.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Se `can_buy` deve exigir que a compra mantenha a conta do comprador viva
+        /// (`Preserve`) ou aceitar esvaziá-la (`Expendable`). `buy_kitty` escolhe por chamada,
+        /// com `keep_alive`.
+        type BuyPreservation: Get<Preservation>;
+
+        /// Quantos blocos sem atividade tornam os Kitties de uma conta reatribuíveis pela
+        /// origem root.
+        #[pallet::constant]
+        type InactivityThreshold: Get<BlockNumberFor<Self>>;
+
+        /// Avisado de cada transferência, podendo recusá-la.
+        type OnReceive: OnKittyReceived<Self::AccountId>;
+
+        /// Número máximo de Kitties em circulação ao mesmo tempo.
+        #[pallet::constant]
+        type MaxSupply: Get<u32>;
+
+        /// Depósito cobrado do dono a cada criação, guardado na conta do pallet até a queima.
+        /// Zero desativa o depósito.
+        #[pallet::constant]
+        type MintDeposit: Get<BalanceOf<Self>>;
+
+        /// Fração do depósito devolvida ao dono na queima; o resto vai para `FeeResolver`.
+        #[pallet::constant]
+        type BurnRefundRatio: Get<Permill>;
+
+        /// Decide a conta que recebe as taxas: a parte do depósito não devolvida na queima e
+        /// `AccelerateFee`.
+        type FeeResolver: FeeResolver<Self::AccountId>;
+
+        /// Taxa paga a `FeeResolver` para encerrar o tempo de espera de um Kitty com
+        /// `accelerate_cooldown`.
+        #[pallet::constant]
+        type AccelerateFee: Get<BalanceOf<Self>>;
+
+        /// Regra de herança do DNA no cruzamento.
+        type TraitInheritance: TraitMixer;
+
+        /// Geração máxima que um filho pode ter; Kitties criados sem pais são da geração zero.
+        #[pallet::constant]
+        type MaxGeneration: Get<u16>;
+
+        /// Se contas comuns podem criar Kitties com `create_kitty` e `create_kitty_pow`. Com
+        /// `false`, novos Kitties vêm apenas de cruzamentos e de criações pela origem root.
+        #[pallet::constant]
+        type AllowOpenMint: Get<bool>;
+
+        /// Identificador dos itens de outro pallet que podem ser equipados nos Kitties.
+        type ItemId: Member + Parameter + MaxEncodedLen + Copy;
+
+        /// Consulta o dono dos itens, para que só o dono equipe o item.
+        type ItemsInspect: InspectItems<Self::AccountId, Self::ItemId>;
+
+        /// Número máximo de ofertas em aberto por Kitty.
+        #[pallet::constant]
+        type MaxOffersPerKitty: Get<u32>;
+
+        /// Quantas vendas `PriceHistory` guarda por Kitty.
+        #[pallet::constant]
+        type MaxPricePoints: Get<u32>;
+
+        /// Quantos blocos o dono precisa esperar entre dois anúncios ou trocas de preço de um
+        /// Kitty. Tirar o Kitty do mercado nunca espera.
+        #[pallet::constant]
+        type PriceUpdateCooldown: Get<BlockNumberFor<Self>>;
+
+        /// DNAs recusados em qualquer criação, seja aleatória, por prova de trabalho, por
+        /// cruzamento ou por fusão.
+        type DnaFilter: DnaFilter;
+
+        /// Menor taxa aceita por `offer_as_sire`, para evitar cruzamentos gratuitos em massa.
+        #[pallet::constant]
+        type MinSireFee: Get<BalanceOf<Self>>;
+
+        /// Consultado antes de cada transferência; o erro que retornar é o erro da chamada.
+        type TransferValidator: TransferValidator<Self::AccountId>;
+
+        /// Tamanho, em bytes, do DNA dos Kitties, que também é o identificador deles. Precisa
+        /// ser pelo menos `MIN_DNA_LENGTH`.
+        #[pallet::constant]
+        type DnaLength: Get<u32>;
     }
 
+    /// Número de espécies distintas que um DNA pode codificar.
+    pub const SPECIES_COUNT: u8 = 8;
+
+    /// Menor `DnaLength` aceito: as características são lidas dos bytes 0 a 5 do DNA.
+    pub const MIN_DNA_LENGTH: u32 = 6;
+
+    /// Nível máximo que um Kitty alcança com `feed`.
+    pub const MAX_LEVEL: u8 = 100;
+
+    /// Versão do formato de `Kitty` gravada nos Kitties criados agora.
+    pub const KITTY_FORMAT_VERSION: u8 = 2;
+
     /// Define o tipo de saldo usado para preços de Kitties.
-    pub type BalanceOf<T> = <T as Config>::NativeBalance;
+    pub type BalanceOf<T> = <<T as Config>::NativeBalance as fungible::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    /// DNA dos Kitties com o tamanho `DnaLength` do runtime.
+    pub type DnaOf<T> = Dna<<T as Config>::DnaLength>;
 
     /// Estrutura representando um Kitty no armazenamento.
+    ///
+    /// O tamanho codificado tem um orçamento verificado nos testes; dados opcionais ou grandes
+    /// vão em mapas próprios, como `KittyNames`.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
     pub struct Kitty<T: Config> {
-        pub dna: [u8; 32],
+        pub dna: DnaOf<T>,
         pub owner: T::AccountId,
         pub price: Option<BalanceOf<T>>,
+        /// Espécie derivada do DNA na criação, usada para filtros rápidos.
+        pub species: u8,
+        /// Bloco a partir do qual o Kitty pode cruzar novamente.
+        pub cooldown_until: BlockNumberFor<T>,
+        /// Nível do Kitty, que sobe ao ser alimentado, até `MAX_LEVEL`.
+        pub level: u8,
+        /// Bloco da última alimentação, ou zero se o Kitty nunca foi alimentado.
+        pub last_fed: BlockNumberFor<T>,
+        /// Bloco até o qual o Kitty não pode mudar de dono, definido pela origem root.
+        pub transfer_locked_until: Option<BlockNumberFor<T>>,
+        /// Versão do formato em que o Kitty foi gravado, para migrações decidirem por Kitty.
+        pub format_version: u8,
+        /// Item de outro pallet equipado no Kitty.
+        pub equipped: Option<T::ItemId>,
+    }
+
+    /// Todos os dados de um Kitty, sem depender de `Config`, para consultas de runtime API.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+    pub struct KittyDetails<KittyId, AccountId, Balance, BlockNumber> {
+        pub dna: KittyId,
+        pub owner: AccountId,
+        pub price: Option<Balance>,
+        pub species: u8,
+        pub cooldown_until: BlockNumber,
+        pub level: u8,
+        pub last_fed: BlockNumber,
+        pub transfer_locked_until: Option<BlockNumber>,
+        pub format_version: u8,
+    }
+
+    /// Estatísticas agregadas do mercado, retornadas por `market_summary`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+    pub struct MarketSummary<Balance> {
+        /// Kitties em circulação.
+        pub total_kitties: u32,
+        /// Kitties anunciados com preço público.
+        pub total_listed: u32,
+        /// Menor preço anunciado, entre os primeiros `MaxScan` Kitties.
+        pub floor_price: Option<Balance>,
+        /// Soma dos preços de todas as vendas já feitas.
+        pub total_volume: Balance,
+        /// Contas que possuem ao menos um Kitty.
+        pub unique_owners: u32,
+    }
+
+    /// Limites e taxas configurados no runtime, retornados por `config_constants`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+    pub struct ConfigConstants<Balance, BlockNumber> {
+        pub max_kitties_owned: u32,
+        pub max_supply: u32,
+        pub max_generation: u16,
+        pub max_name_length: u32,
+        pub max_memo: u32,
+        pub max_batch_size: u32,
+        pub max_listings_per_account: u32,
+        pub max_offers_per_kitty: u32,
+        pub max_price_points: u32,
+        pub mint_difficulty: u8,
+        pub allow_open_mint: bool,
+        pub breeding_cooldown: BlockNumber,
+        pub feed_cooldown: BlockNumber,
+        pub burn_delay: BlockNumber,
+        pub price_update_cooldown: BlockNumber,
+        pub listing_decay: Permill,
+        pub burn_refund_ratio: Permill,
+        pub mint_deposit: Balance,
+        pub accelerate_fee: Balance,
+        pub min_sire_fee: Balance,
+    }
+
+    /// `KittyDetails` com os tipos do runtime.
+    pub type KittyDetailsOf<T> = KittyDetails<
+        DnaOf<T>,
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+    >;
+
+    impl<T: Config> From<Kitty<T>> for KittyDetailsOf<T> {
+        fn from(kitty: Kitty<T>) -> Self {
+            KittyDetails {
+                dna: kitty.dna,
+                owner: kitty.owner,
+                price: kitty.price,
+                species: kitty.species,
+                cooldown_until: kitty.cooldown_until,
+                level: kitty.level,
+                last_fed: kitty.last_fed,
+                transfer_locked_until: kitty.transfer_locked_until,
+                format_version: kitty.format_version,
+            }
+        }
+    }
+
+    /// Reserva de um Kitty para um comprador específico até um bloco de expiração.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Reservation<T: Config> {
+        pub buyer: T::AccountId,
+        pub price: BalanceOf<T>,
+        pub expiry: BlockNumberFor<T>,
+    }
+
+    /// Venda privada de um Kitty, que apenas `buyer` pode concluir.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PrivateSale<T: Config> {
+        pub buyer: T::AccountId,
+        pub price: BalanceOf<T>,
+    }
+
+    /// Oferta de compra feita por um comprador, com o valor guardado na conta do pallet.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Offer<T: Config> {
+        pub amount: BalanceOf<T>,
+        /// Bloco a partir do qual a oferta não pode mais ser aceita.
+        pub expiry: BlockNumberFor<T>,
+    }
+
+    /// Leilão em degraus: o preço cai `step_size` a cada `step_interval` blocos completos desde
+    /// `started_at`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct StepAuction<T: Config> {
+        pub start_price: BalanceOf<T>,
+        pub step_size: Permill,
+        pub step_interval: BlockNumberFor<T>,
+        pub started_at: BlockNumberFor<T>,
+    }
+
+    /// Motivo de uma transferência, informado no evento `Transferred`.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+    pub enum TransferReason {
+        /// Compra via `buy_kitty`.
+        Sale,
+        /// Transferência com memo via `transfer_with_memo`.
+        Gift,
+        /// Transferência comum feita pelo próprio dono.
+        Direct,
+        /// Transferência feita pela origem root em nome do dono.
+        Force,
+    }
+
+    /// Motivo pelo qual uma compra falharia, retornado por `can_buy`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+    pub enum BuyError {
+        /// O Kitty não existe.
+        NoKitty,
+        /// O comprador já é o dono do Kitty.
+        AlreadyOwner,
+        /// O Kitty não está à venda.
+        NotForSale,
+        /// O Kitty está reservado para outro comprador.
+        ReservedForAnother,
+        /// O comprador não tem saldo suficiente.
+        InsufficientFunds,
+        /// O Kitty tem uma queima pendente.
+        BurnPending,
+        /// O Kitty está com a transferência bloqueada.
+        TransferLocked,
+    }
+
+    /// Motivo pelo qual um cruzamento falharia, retornado por `can_breed`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+    pub enum BreedError {
+        /// Algum dos pais não existe.
+        NoKitty,
+        /// Os dois pais são o mesmo Kitty.
+        SameParents,
+        /// Os pais têm donos diferentes.
+        DifferentOwners,
+        /// A conta não é o dono dos pais nem está autorizada nos dois.
+        NotAuthorized,
+        /// Algum dos pais está no tempo de espera de cruzamento.
+        OnCooldown,
+        /// O filho passaria da geração `MaxGeneration`.
+        MaxGenerationReached,
+        /// A circulação já chegou a `MaxSupply`.
+        TooManyKitties,
+        /// O dono já possui o máximo de Kitties.
+        TooManyOwned,
     }
 
     /// Armazena o contador global de Kitties.
@@ -57,35 +435,258 @@ pub mod pallet {
     #[pallet::getter(fn kitty_count)]
     pub(super) type CountForKitties<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// Armazena quantos Kitties já foram queimados.
+    #[pallet::storage]
+    pub(super) type TotalBurned<T: Config> = StorageValue<_, u32, ValueQuery>;
+
     /// Mapeia um DNA de Kitty para a sua estrutura.
     #[pallet::storage]
     #[pallet::getter(fn kitties)]
-    pub(super) type Kitties<T: Config> = StorageMap<_, Blake2_128Concat, [u8; 32], Kitty<T>>;
+    pub(super) type Kitties<T: Config> = StorageMap<_, Blake2_128Concat, DnaOf<T>, Kitty<T>>;
 
-    /// Mapeia cada conta para a lista de Kitties que possui.
+    /// Kitties de cada conta, para verificar e alterar a posse em tempo constante.
+    ///
+    /// A enumeração segue a ordem de armazenamento, e não a ordem de chegada dos Kitties.
     #[pallet::storage]
-    #[pallet::getter(fn kitties_owned)]
-    pub(super) type KittiesOwned<T: Config> = StorageMap<
+    pub(super) type OwnedKitties<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, DnaOf<T>, ()>;
+
+    /// Quantos Kitties cada conta possui; contas sem Kitties não têm entrada.
+    #[pallet::storage]
+    pub(super) type OwnedCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Preço da última venda de cada Kitty.
+    #[pallet::storage]
+    pub(super) type LastSalePrice<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, BalanceOf<T>>;
+
+    /// Quantos Kitties já foram criados para cada conta, incluído no DNA por `gen_dna`.
+    #[pallet::storage]
+    pub(super) type MintNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Bloco do último anúncio ou troca de preço de cada Kitty, para `PriceUpdateCooldown`.
+    #[pallet::storage]
+    pub(super) type LastPriceUpdate<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, BlockNumberFor<T>>;
+
+    /// Bloco e preço das últimas `MaxPricePoints` vendas de cada Kitty, da mais antiga para a
+    /// mais recente.
+    #[pallet::storage]
+    pub(super) type PriceHistory<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
-        T::AccountId,
-        BoundedVec<[u8; 32], T::MaxKittiesOwned>,
+        DnaOf<T>,
+        BoundedVec<(BlockNumberFor<T>, BalanceOf<T>), T::MaxPricePoints>,
         ValueQuery,
     >;
 
+    /// Índice de Kitties por espécie.
+    #[pallet::storage]
+    pub(super) type SpeciesIndex<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u8, Blake2_128Concat, DnaOf<T>, ()>;
+
+    /// Último Kitty visitado pela depreciação, de onde o próximo bloco continua.
+    #[pallet::storage]
+    pub(super) type DecayCursor<T: Config> = StorageValue<_, DnaOf<T>>;
+
+    /// Última oferta visitada pela limpeza de ofertas expiradas, de onde o próximo bloco
+    /// continua.
+    #[pallet::storage]
+    pub(super) type OfferCleanupCursor<T: Config> = StorageValue<_, (DnaOf<T>, T::AccountId)>;
+
+    /// Limites de posse personalizados, sempre menores ou iguais a `MaxKittiesOwned`.
+    #[pallet::storage]
+    pub(super) type OwnedLimits<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+    /// Kitties oferecidos como reprodutores, com a taxa cobrada por cruzamento.
+    #[pallet::storage]
+    pub(super) type SireListings<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, BalanceOf<T>>;
+
+    /// Reservas ativas ou expiradas de Kitties para compradores específicos.
+    #[pallet::storage]
+    pub(super) type Reservations<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, Reservation<T>>;
+
+    /// Nomes dados aos Kitties pelos seus donos.
+    #[pallet::storage]
+    pub(super) type KittyNames<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, BoundedVec<u8, T::MaxNameLength>>;
+
+    /// Queimas pendentes, com o bloco a partir do qual podem ser finalizadas.
+    #[pallet::storage]
+    pub(super) type PendingBurns<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, BlockNumberFor<T>>;
+
+    /// Quantos Kitties cada conta tem anunciados com preço público.
+    #[pallet::storage]
+    pub(super) type ListingCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+    /// Conta autorizada a transferir cada Kitty em nome do dono.
+    #[pallet::storage]
+    pub(super) type Approvals<T: Config> = StorageMap<_, Blake2_128Concat, DnaOf<T>, T::AccountId>;
+
+    /// Ofertas de compra em aberto, por Kitty e por comprador.
+    ///
+    /// Ofertas sobrevivem à queima do Kitty; o comprador recupera os fundos com
+    /// `withdraw_offer`.
+    #[pallet::storage]
+    pub(super) type Offers<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, DnaOf<T>, Blake2_128Concat, T::AccountId, Offer<T>>;
+
+    /// Número de ofertas em aberto por Kitty, limitado por `MaxOffersPerKitty`.
+    #[pallet::storage]
+    pub(super) type OfferCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, u32, ValueQuery>;
+
+    /// Último bloco em que cada conta concluiu uma chamada assinada deste pallet. Registrado só
+    /// depois do sucesso da chamada; `purge_account` o apaga.
+    #[pallet::storage]
+    pub(super) type LastActivity<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// Total de Kitties anunciados com preço público, em todas as contas.
+    #[pallet::storage]
+    pub(super) type ListedCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Soma dos preços de todas as vendas já feitas.
+    #[pallet::storage]
+    pub(super) type TotalVolume<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Número de contas que possuem ao menos um Kitty.
+    #[pallet::storage]
+    pub(super) type OwnerCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Depósito pago na criação de cada Kitty, devolvido em parte na queima.
+    #[pallet::storage]
+    pub(super) type Deposits<T: Config> = StorageMap<_, Blake2_128Concat, DnaOf<T>, BalanceOf<T>>;
+
+    /// Kitties anunciados em leilão em degraus, cujo preço vem de `current_step_price` e não
+    /// sofre `ListingDecay`.
+    #[pallet::storage]
+    pub(super) type StepAuctions<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, StepAuction<T>>;
+
+    /// Geração de cada Kitty: uma a mais que a do pai mais novo. Ausente significa geração zero.
+    #[pallet::storage]
+    pub(super) type Generations<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, u16, ValueQuery>;
+
+    /// Soma das ofertas em aberto de cada conta, guardadas na conta do pallet.
+    #[pallet::storage]
+    pub(super) type OfferedTotal<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Vendas privadas, fora dos anúncios públicos.
+    #[pallet::storage]
+    pub(super) type PrivateSales<T: Config> =
+        StorageMap<_, Blake2_128Concat, DnaOf<T>, PrivateSale<T>>;
+
+    /// Kitty em que cada item está equipado, para que um item fique em um Kitty só.
+    #[pallet::storage]
+    pub(super) type EquippedItems<T: Config> = StorageMap<_, Blake2_128Concat, T::ItemId, DnaOf<T>>;
+
     /// Eventos do pallet.
+    ///
+    /// Toda mudança de dono emite um único `Transferred`, cujo `reason` diz como ela ocorreu.
+    /// Eventos como `Sold` e `TransferredWithMemo` apenas complementam com o preço ou o memo; a
+    /// exceção é `transfer_batch` com `EmitPerItemEvents` desligado.
+    ///
+    /// `Created` é publicado com o tópico `kitty_topic(kitty_id)`.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        Created { owner: T::AccountId },
-        Transferred { from: T::AccountId, to: T::AccountId, kitty_id: [u8; 32] },
-        PriceSet { owner: T::AccountId, kitty_id: [u8; 32], new_price: Option<BalanceOf<T>> },
-        Sold { buyer: T::AccountId, kitty_id: [u8; 32], price: BalanceOf<T> },
+        Created { owner: T::AccountId, kitty_id: DnaOf<T> },
+        Transferred {
+            from: T::AccountId,
+            to: T::AccountId,
+            kitty_id: DnaOf<T>,
+            reason: TransferReason,
+            /// Kitties que `from` ainda possui depois da transferência.
+            from_remaining: u32,
+            /// Kitties que `to` possui depois da transferência.
+            to_total: u32,
+        },
+        PriceSet { owner: T::AccountId, kitty_id: DnaOf<T>, new_price: Option<BalanceOf<T>> },
+        Sold {
+            buyer: T::AccountId,
+            /// Dono do Kitty antes da venda.
+            seller: T::AccountId,
+            kitty_id: DnaOf<T>,
+            price: BalanceOf<T>,
+        },
+        SireFeeSet { owner: T::AccountId, kitty_id: DnaOf<T>, fee: Option<BalanceOf<T>> },
+        Bred { owner: T::AccountId, kitty_id: DnaOf<T>, parents: [DnaOf<T>; 2] },
+        Reserved {
+            kitty_id: DnaOf<T>,
+            buyer: T::AccountId,
+            price: BalanceOf<T>,
+            expiry: BlockNumberFor<T>,
+        },
+        ReservationCancelled { kitty_id: DnaOf<T> },
+        Burned { owner: T::AccountId, kitty_id: DnaOf<T> },
+        OwnedLimitSet { who: T::AccountId, limit: Option<u32> },
+        BatchTransferred { from: T::AccountId, to: T::AccountId, count: u32 },
+        NameSet { owner: T::AccountId, kitty_id: DnaOf<T>, name: BoundedVec<u8, T::MaxNameLength> },
+        TransferredWithMemo {
+            from: T::AccountId,
+            to: T::AccountId,
+            kitty_id: DnaOf<T>,
+            memo: BoundedVec<u8, T::MaxMemo>,
+        },
+        BurnRequested { owner: T::AccountId, kitty_id: DnaOf<T>, executable_at: BlockNumberFor<T> },
+        BurnCancelled { kitty_id: DnaOf<T> },
+        PrivateSaleCreated { kitty_id: DnaOf<T>, buyer: T::AccountId, price: BalanceOf<T> },
+        PrivateSaleCancelled { kitty_id: DnaOf<T> },
+        Fed { kitty_id: DnaOf<T>, new_level: u8 },
+        Approved { owner: T::AccountId, kitty_id: DnaOf<T>, spender: Option<T::AccountId> },
+        TransferLockSet { kitty_id: DnaOf<T>, until: Option<BlockNumberFor<T>> },
+        OfferMade {
+            kitty_id: DnaOf<T>,
+            bidder: T::AccountId,
+            amount: BalanceOf<T>,
+            expiry: BlockNumberFor<T>,
+        },
+        OfferWithdrawn { kitty_id: DnaOf<T>, bidder: T::AccountId },
+        OfferAccepted { kitty_id: DnaOf<T>, bidder: T::AccountId, amount: BalanceOf<T> },
+        OfferRejected { kitty_id: DnaOf<T>, bidder: T::AccountId },
+        /// Uma oferta expirada foi devolvida ao comprador em `on_idle`.
+        ExpiredOfferRefunded { kitty_id: DnaOf<T>, bidder: T::AccountId, amount: BalanceOf<T> },
+        Claimed { kitty_id: DnaOf<T>, from: T::AccountId, to: T::AccountId },
+        /// O Kitty criado levou a circulação a `MaxSupply`; novas criações falham até alguma
+        /// queima.
+        SupplyCapReached { total: u32 },
+        /// Parte do depósito de um Kitty queimado devolvida ao dono; o resto foi para
+        /// `FeeResolver`.
+        DepositRefunded { owner: T::AccountId, kitty_id: DnaOf<T>, amount: BalanceOf<T> },
+        StepAuctionStarted {
+            kitty_id: DnaOf<T>,
+            start_price: BalanceOf<T>,
+            step_size: Permill,
+            step_interval: BlockNumberFor<T>,
+        },
+        CooldownAccelerated { owner: T::AccountId, kitty_id: DnaOf<T>, fee: BalanceOf<T> },
+        Merged { burned: [DnaOf<T>; 2], created: DnaOf<T> },
+        Equipped { owner: T::AccountId, kitty_id: DnaOf<T>, item_id: T::ItemId },
+        Unequipped { owner: T::AccountId, kitty_id: DnaOf<T>, item_id: T::ItemId },
+        /// A origem root redefiniu o dono do Kitty e reconstruiu a posse das contas envolvidas.
+        OwnerRepaired { kitty_id: DnaOf<T>, old_owner: T::AccountId, new_owner: T::AccountId },
+        /// A conta, sem Kitties, apagou o seu registro de atividade.
+        AccountPurged { who: T::AccountId },
+        /// Resumo de um `sweep`, emitido depois dos `Sold` de cada compra.
+        Swept { buyer: T::AccountId, count: u32, total_spent: BalanceOf<T> },
+        /// A origem root retirou taxas acumuladas na conta de taxas do pallet.
+        FeesWithdrawn { to: T::AccountId, amount: BalanceOf<T> },
+        /// Um Kitty cujo dono não existe mais na cadeia foi reatribuído a `to`.
+        Rehomed { kitty_id: DnaOf<T>, from: T::AccountId, to: T::AccountId },
     }
 
     /// Erros do pallet.
     #[pallet::error]
     pub enum Error<T> {
+        /// A circulação já atingiu `MaxSupply`.
         TooManyKitties,
         DuplicateKitty,
         TooManyOwned,
@@ -94,100 +695,838 @@ pub mod pallet {
         NotOwner,
         NotForSale,
         MaxPriceTooLow,
+        /// O Kitty não está oferecido como reprodutor.
+        NotSire,
+        /// Um Kitty não pode cruzar consigo mesmo.
+        SameParents,
+        /// Um dos pais ainda está no período de espera entre cruzamentos.
+        KittyOnCooldown,
+        /// Apenas a origem root pode agir em nome de outra conta.
+        OnBehalfOfNotAllowed,
+        /// A origem root precisa indicar a conta em nome da qual age.
+        MissingOnBehalfOf,
+        /// O Kitty está reservado para outro comprador.
+        ReservedForAnother,
+        /// O Kitty não tem reserva.
+        NoReservation,
+        /// A expiração da reserva precisa estar no futuro.
+        ExpiryInPast,
+        /// O DNA gerado pelo nonce não atinge a dificuldade exigida.
+        PowTooEasy,
+        /// O limite personalizado não pode passar de `MaxKittiesOwned`.
+        LimitTooHigh,
+        /// O Kitty nunca foi vendido.
+        NoSaleHistory,
+        /// Um Kitty não pode ser anunciado por preço zero.
+        ZeroPrice,
+        /// O nome contém caracteres recusados por `NamePolicy`.
+        InvalidName,
+        /// O Kitty tem uma queima pendente e não pode ser transferido nem vendido.
+        BurnPending,
+        /// O Kitty não tem queima pendente.
+        NoPendingBurn,
+        /// O atraso da queima ainda não terminou.
+        BurnDelayNotElapsed,
+        /// O Kitty não tem venda privada.
+        NoPrivateSale,
+        /// O Kitty foi alimentado há menos de `FeedCooldown` blocos.
+        FeedTooSoon,
+        /// A conta já tem `MaxListingsPerAccount` Kitties anunciados.
+        TooManyListings,
+        /// O chamador não está autorizado a transferir o Kitty.
+        NotApproved,
+        /// O Kitty está com a transferência bloqueada até um bloco futuro.
+        TransferLocked,
+        /// Não há oferta deste comprador para o Kitty.
+        NoOffer,
+        /// O comprador já tem uma oferta em aberto para o Kitty.
+        DuplicateOffer,
+        /// A oferta expirou e não pode mais ser aceita.
+        OfferExpired,
+        /// O dono teve atividade há menos de `InactivityThreshold` blocos.
+        OwnerStillActive,
+        /// O intervalo entre os degraus de um leilão não pode ser zero.
+        ZeroStepInterval,
+        /// O Kitty mudou de dono depois que o comprador indicou o vendedor esperado.
+        SellerChanged,
+        /// O Kitty não está em tempo de espera de cruzamento.
+        NotOnCooldown,
+        /// Com `keep_alive`, o pagamento deixaria o comprador abaixo do depósito existencial.
+        WouldReapBuyer,
+        /// O filho passaria da geração `MaxGeneration`.
+        MaxGenerationReached,
+        /// `AllowOpenMint` está desligado, então apenas a origem root cria Kitties.
+        OpenMintDisabled,
+        /// O chamador não é o dono dos pais nem está autorizado nos dois.
+        NotAuthorizedToBreed,
+        /// O chamador não é o dono do item.
+        NotItemOwner,
+        /// O Kitty não tem item equipado.
+        NothingEquipped,
+        /// O item já está equipado em outro Kitty.
+        ItemAlreadyEquipped,
+        /// O Kitty já tem `MaxOffersPerKitty` ofertas em aberto.
+        TooManyOffers,
+        /// O preço do Kitty mudou há menos de `PriceUpdateCooldown` blocos.
+        PriceUpdateTooSoon,
+        /// O DNA gerado foi recusado por `DnaFilter`.
+        DnaRejected,
+        /// A taxa de reprodutor é menor que `MinSireFee`.
+        SireFeeTooLow,
+        /// A conta ainda possui Kitties.
+        NonEmptyAccount,
+        /// A conta do dono ainda existe em `frame_system`.
+        OwnerAccountExists,
     }
 
-    /// Funções auxiliares do pallet.
-    impl<T: Config> Pallet<T> {
-        /// Gera um DNA único para o Kitty.
-        pub fn gen_dna() -> [u8; 32] {
-            let unique_payload = (
-                frame_system::Pallet::<T>::parent_hash(),
-                frame_system::Pallet::<T>::block_number(),
-                frame_system::Pallet::<T>::extrinsic_index(),
-                CountForKitties::<T>::get(),
-            );
-            BlakeTwo256::hash_of(&unique_payload).into()
-        }
-
-        /// Realiza a criação do Kitty e adiciona no armazenamento.
-        pub fn mint(owner: T::AccountId, dna: [u8; 32]) -> DispatchResult {
-            ensure!(!Kitties::<T>::contains_key(dna), Error::<T>::DuplicateKitty);
-            let current_count = CountForKitties::<T>::get();
-            let new_count = current_count
-                .checked_add(1)
-                .ok_or(Error::<T>::TooManyKitties)?;
-
-            let mut owned = KittiesOwned::<T>::get(&owner);
-            owned
-                .try_push(dna)
-                .map_err(|_| Error::<T>::TooManyOwned)?;
-
-            Kitties::<T>::insert(
-                dna,
-                Kitty {
-                    dna,
-                    owner: owner.clone(),
-                    price: None,
-                },
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Self::decay_listings()
+        }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::cleanup_expired_offers(remaining_weight)
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::DnaLength::get() >= MIN_DNA_LENGTH,
+                "`DnaLength` não cobre os bytes das características"
             );
-            KittiesOwned::<T>::insert(&owner, owned);
-            CountForKitties::<T>::put(new_count);
+        }
 
-            Self::deposit_event(Event::Created { owner });
-            Ok(())
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
         }
     }
 
     /// Funções que podem ser chamadas externamente via extrinsics.
+    ///
+    /// As chamadas de jogo aceitam `on_behalf_of`, válido apenas com a origem root, para que
+    /// testes de integração e operações de recuperação possam agir em nome de uma conta.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Cria um novo Kitty.
+        ///
+        /// Com `AllowOpenMint` desligado, apenas a origem root, em nome de uma conta, pode criar.
         #[pallet::call_index(0)]
         #[pallet::weight(10_000)]
-        pub fn create_kitty(origin: OriginFor<T>) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-            let dna = Self::gen_dna();
-            Self::mint(who, dna)?;
+        pub fn create_kitty(
+            origin: OriginFor<T>,
+            on_behalf_of: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let open_mint = on_behalf_of.is_none();
+            let who = Self::ensure_caller(origin, on_behalf_of)?;
+            ensure!(!open_mint || T::AllowOpenMint::get(), Error::<T>::OpenMintDisabled);
+            let dna = Self::gen_dna(&who);
+            Self::mint(who.clone(), dna)?;
+            if open_mint {
+                Self::note_activity(&who);
+            }
             Ok(())
         }
 
         /// Transfere um Kitty para outro usuário.
+        ///
+        /// Com a posse em `OwnedKitties`, o custo não depende de quantos Kitties o remetente tem.
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::transfer())]
         pub fn transfer(
             origin: OriginFor<T>,
             to: T::AccountId,
-            kitty_id: [u8; 32],
+            kitty_id: DnaOf<T>,
+            on_behalf_of: Option<T::AccountId>,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-            // Implement transfer logic here
+            // `on_behalf_of` só é aceito com a origem root.
+            let signed = on_behalf_of.is_none();
+            let reason = if signed { TransferReason::Direct } else { TransferReason::Force };
+            let who = Self::ensure_caller(origin, on_behalf_of)?;
+            Self::do_transfer(who.clone(), to, kitty_id, reason)?;
+            if signed {
+                Self::note_activity(&who);
+            }
             Ok(())
         }
 
         /// Define o preço de um Kitty.
+        ///
+        /// Repetir o preço atual não altera nada nem emite evento, e a chamada não é cobrada, a
+        /// menos que encerre um leilão em degraus.
         #[pallet::call_index(2)]
         #[pallet::weight(10_000)]
         pub fn set_price(
             origin: OriginFor<T>,
-            kitty_id: [u8; 32],
+            kitty_id: DnaOf<T>,
             new_price: Option<BalanceOf<T>>,
+            on_behalf_of: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let signed = on_behalf_of.is_none();
+            let who = Self::ensure_caller(origin, on_behalf_of)?;
+            let changed = Self::do_set_price(who.clone(), kitty_id, new_price)?;
+            if signed {
+                Self::note_activity(&who);
+            }
+            if !changed {
+                return Ok(Pays::No.into());
+            }
+            Ok(().into())
+        }
+
+        /// Compra um Kitty de outro usuário, devolvendo as ofertas em aberto por ele.
+        ///
+        /// A compra falha com `SellerChanged` se o dono não for mais `expected_seller`, para que
+        /// uma troca de dono logo antes da compra não faça o comprador pagar a um estranho.
+        ///
+        /// Com `keep_alive`, o pagamento precisa deixar o comprador acima do depósito
+        /// existencial, falhando com `WouldReapBuyer` caso contrário; sem ele, a conta pode ser
+        /// esvaziada.
+        ///
+        /// O pallet ainda não cobra taxas nem royalties, então há um só caminho de custo, mais a
+        /// devolução de até `MaxOffersPerKitty` ofertas; o peso cobra esse pior caso e devolve a
+        /// diferença conforme as ofertas realmente devolvidas.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::buy_kitty().saturating_add(Pallet::<T>::batch_weight(
+            T::MaxOffersPerKitty::get()
+        )))]
+        pub fn buy_kitty(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            expected_seller: T::AccountId,
+            max_price: BalanceOf<T>,
+            keep_alive: bool,
+            on_behalf_of: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let signed = on_behalf_of.is_none();
+            let who = Self::ensure_caller(origin, on_behalf_of)?;
+            let refunded =
+                Self::do_buy_kitty(who.clone(), kitty_id, expected_seller, max_price, keep_alive)?;
+            if signed {
+                Self::note_activity(&who);
+            }
+            Ok(Some(T::WeightInfo::buy_kitty().saturating_add(Self::batch_weight(refunded))).into())
+        }
+
+        /// Cruza dois Kitties do mesmo dono, criando um filho para o dono.
+        ///
+        /// O chamador precisa ser o dono ou estar autorizado com `approve` nos dois pais.
+        ///
+        /// Com a posse em `OwnedKitties`, o custo não depende de quantos Kitties o chamador tem.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::breed_kitty())]
+        pub fn breed_kitty(
+            origin: OriginFor<T>,
+            parent_1: DnaOf<T>,
+            parent_2: DnaOf<T>,
+            on_behalf_of: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let signed = on_behalf_of.is_none();
+            let who = Self::ensure_caller(origin, on_behalf_of)?;
+            Self::do_breed_kitty(who.clone(), parent_1, parent_2)?;
+            if signed {
+                Self::note_activity(&who);
+            }
+            Ok(())
+        }
+
+        /// Oferece um Kitty como reprodutor mediante uma taxa, ou remove a oferta com `None`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(10_000)]
+        pub fn offer_as_sire(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            fee: Option<BalanceOf<T>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            // Implement set_price logic here
+            Self::do_offer_as_sire(who.clone(), kitty_id, fee)?;
+            Self::note_activity(&who);
             Ok(())
         }
 
-        /// Compra um Kitty de outro usuário.
-        #[pallet::call_index(3)]
+        /// Cruza um Kitty do chamador com um reprodutor ofertado, pagando a taxa ao dono dele.
+        #[pallet::call_index(6)]
         #[pallet::weight(10_000)]
-        pub fn buy_kitty(
+        pub fn breed_with_sire(
             origin: OriginFor<T>,
-            kitty_id: [u8; 32],
-            max_price: BalanceOf<T>,
+            my_kitty: DnaOf<T>,
+            sire_id: DnaOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_breed_with_sire(who.clone(), my_kitty, sire_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Reserva um Kitty para `buyer` por `price` até o bloco `expiry`.
+        #[pallet::call_index(7)]
+        #[pallet::weight(10_000)]
+        pub fn reserve_for(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            buyer: T::AccountId,
+            price: BalanceOf<T>,
+            expiry: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_reserve_for(who.clone(), kitty_id, buyer, price, expiry)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Cancela a reserva de um Kitty.
+        #[pallet::call_index(8)]
+        #[pallet::weight(10_000)]
+        pub fn cancel_reservation(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_cancel_reservation(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Queima um Kitty do chamador, removendo-o de circulação.
+        #[pallet::call_index(9)]
+        #[pallet::weight(10_000)]
+        pub fn burn_kitty(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_burn_kitty(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Cria um Kitty cujo DNA é `hash(chamador, nonce)`, desde que ele comece com
+        /// `MintDifficulty` bytes zero.
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)]
+        pub fn create_kitty_pow(origin: OriginFor<T>, nonce: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(T::AllowOpenMint::get(), Error::<T>::OpenMintDisabled);
+            let dna = Self::pow_dna(&who, nonce);
+            ensure!(
+                Self::meets_difficulty(&dna, T::MintDifficulty::get()),
+                Error::<T>::PowTooEasy
+            );
+            Self::mint(who.clone(), dna)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Define (ou remove, com `None`) um limite de posse personalizado para uma conta.
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)]
+        pub fn set_owned_limit(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            limit: Option<u32>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_set_owned_limit(who, limit)?;
+            Ok(())
+        }
+
+        /// Transfere vários Kitties do chamador para `to`. Falha por inteiro se qualquer
+        /// transferência falhar.
+        ///
+        /// Cada item custa o mesmo que uma chamada `transfer`.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::transfer().saturating_mul(kitty_ids.len() as u64))]
+        pub fn transfer_batch(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            kitty_ids: BoundedVec<DnaOf<T>, T::MaxBatchSize>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_transfer_batch(who.clone(), to, kitty_ids)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Define o preço de um Kitty como `percent`% do preço da sua última venda
+        /// (por exemplo, `110` anuncia por 110% da última venda).
+        #[pallet::call_index(13)]
+        #[pallet::weight(10_000)]
+        pub fn set_price_relative(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            percent: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let last_sale = LastSalePrice::<T>::get(&kitty_id).ok_or(Error::<T>::NoSaleHistory)?;
+            let new_price = Self::percent_of(last_sale, percent);
+            Self::do_set_price(who.clone(), kitty_id, Some(new_price))?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Dá um nome a um Kitty, validado por `NamePolicy`.
+        #[pallet::call_index(14)]
+        #[pallet::weight(10_000)]
+        pub fn set_name(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            name: BoundedVec<u8, T::MaxNameLength>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_set_name(who.clone(), kitty_id, name)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Transfere um Kitty junto com um memo.
+        ///
+        /// O memo não é armazenado, apenas emitido em `TransferredWithMemo`, logo após o
+        /// `Transferred` com motivo `Gift`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn transfer_with_memo(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            kitty_id: DnaOf<T>,
+            memo: BoundedVec<u8, T::MaxMemo>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_transfer_with_memo(who.clone(), to, kitty_id, memo)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Agenda a queima de um Kitty do chamador para daqui a `BurnDelay` blocos.
+        ///
+        /// Enquanto a queima estiver pendente o Kitty não pode ser transferido nem vendido.
+        #[pallet::call_index(16)]
+        #[pallet::weight(10_000)]
+        pub fn request_burn(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_request_burn(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Cancela a queima pendente de um Kitty do chamador.
+        #[pallet::call_index(17)]
+        #[pallet::weight(10_000)]
+        pub fn cancel_burn(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_cancel_burn(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Executa uma queima pendente cujo atraso já terminou. Qualquer conta pode chamar.
+        #[pallet::call_index(18)]
+        #[pallet::weight(10_000)]
+        pub fn finalize_burn(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_finalize_burn(kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Oferece um Kitty do chamador apenas para `buyer`, pelo preço `price`.
+        ///
+        /// Enquanto a venda privada existir, qualquer outro comprador recebe `NotForSale`, mesmo
+        /// que o Kitty tenha um preço público.
+        #[pallet::call_index(19)]
+        #[pallet::weight(10_000)]
+        pub fn private_sale(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            buyer: T::AccountId,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_private_sale(who.clone(), kitty_id, buyer, price)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Cancela a venda privada de um Kitty do chamador.
+        #[pallet::call_index(20)]
+        #[pallet::weight(10_000)]
+        pub fn cancel_private_sale(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_cancel_private_sale(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Alimenta um Kitty do chamador, subindo o seu nível em um.
+        #[pallet::call_index(21)]
+        #[pallet::weight(10_000)]
+        pub fn feed(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_feed(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Autoriza (ou desautoriza, com `None`) `spender` a transferir um Kitty do chamador e a
+        /// cruzá-lo, se também estiver autorizado no outro pai.
+        ///
+        /// A autorização é removida quando o Kitty muda de dono.
+        #[pallet::call_index(22)]
+        #[pallet::weight(Pallet::<T>::approve_weight())]
+        pub fn approve(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            spender: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_approve(who.clone(), kitty_id, spender)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Transfere para `to` um Kitty que o chamador foi autorizado a transferir.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn transfer_from(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            to: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_transfer_from(who.clone(), kitty_id, to)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Autoriza `spender` a transferir vários Kitties do chamador. Falha por inteiro se
+        /// algum deles não for do chamador.
+        ///
+        /// Cada item custa o mesmo que uma chamada `approve`.
+        #[pallet::call_index(24)]
+        #[pallet::weight(Pallet::<T>::approve_weight().saturating_mul(kitty_ids.len() as u64))]
+        pub fn bulk_approve(
+            origin: OriginFor<T>,
+            kitty_ids: BoundedVec<DnaOf<T>, T::MaxBatchSize>,
+            spender: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            for kitty_id in kitty_ids {
+                Self::do_approve(who.clone(), kitty_id, Some(spender.clone()))?;
+            }
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Bloqueia a transferência de um Kitty até o bloco `until`, ou libera com `None`.
+        ///
+        /// Apenas a origem root pode chamar, e o bloqueio também impede a venda. Chamar de novo
+        /// substitui o bloqueio anterior, encurtando ou estendendo o prazo.
+        #[pallet::call_index(25)]
+        #[pallet::weight(10_000)]
+        pub fn lock_transfer(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            until: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_lock_transfer(kitty_id, until)?;
+            Ok(())
+        }
+
+        /// Oferece `amount` por um Kitty, válido até o bloco `expiry`.
+        ///
+        /// O valor sai da conta do chamador e fica na conta do pallet até a oferta ser aceita,
+        /// retirada ou rejeitada.
+        #[pallet::call_index(26)]
+        #[pallet::weight(10_000)]
+        pub fn make_offer(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            amount: BalanceOf<T>,
+            expiry: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_make_offer(who.clone(), kitty_id, amount, expiry)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Retira a oferta do chamador, devolvendo o valor. Funciona mesmo depois da expiração
+        /// ou da queima do Kitty.
+        #[pallet::call_index(27)]
+        #[pallet::weight(10_000)]
+        pub fn withdraw_offer(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_withdraw_offer(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Aceita a oferta de `bidder` por um Kitty do chamador, vendendo-o pelo valor ofertado.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn accept_offer(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            bidder: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_accept_offer(who.clone(), kitty_id, bidder)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Rejeita a oferta de `bidder` por um Kitty do chamador, devolvendo o valor a ele.
+        #[pallet::call_index(29)]
+        #[pallet::weight(10_000)]
+        pub fn reject_offer(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            bidder: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_reject_offer(who.clone(), kitty_id, bidder)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Reatribui a `new_owner` um Kitty cujo dono está inativo há mais de
+        /// `InactivityThreshold` blocos. Apenas a origem root pode chamar.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn claim_inactive(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_claim_inactive(kitty_id, new_owner)?;
+            Ok(())
+        }
+
+        /// Aceita, em cada Kitty do chamador, a maior oferta válida e rejeita as demais,
+        /// devolvendo os valores. Kitties sem oferta válida são pulados.
+        ///
+        /// Cada Kitty tem no máximo `MaxOffersPerKitty` ofertas; o peso cobra esse pior caso e
+        /// devolve a diferença conforme as ofertas realmente examinadas.
+        #[pallet::call_index(31)]
+        #[pallet::weight(Pallet::<T>::batch_weight(
+            (kitty_ids.len() as u32).saturating_mul(T::MaxOffersPerKitty::get().saturating_add(1))
+        ))]
+        pub fn accept_best_offers(
+            origin: OriginFor<T>,
+            kitty_ids: BoundedVec<DnaOf<T>, T::MaxBatchSize>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let kitties = kitty_ids.len() as u32;
+            let scanned = Self::do_accept_best_offers(who.clone(), kitty_ids)?;
+            Self::note_activity(&who);
+            Ok(Some(Self::batch_weight(kitties.saturating_add(scanned))).into())
+        }
+
+        /// Anuncia um Kitty do chamador em leilão em degraus: começa em `start_price` e cai
+        /// `step_size` a cada `step_interval` blocos, nunca abaixo de uma unidade.
+        ///
+        /// Definir o preço com `set_price` encerra o leilão.
+        #[pallet::call_index(32)]
+        #[pallet::weight(10_000)]
+        pub fn start_step_auction(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            start_price: BalanceOf<T>,
+            step_size: Permill,
+            step_interval: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_start_step_auction(
+                who.clone(),
+                kitty_id,
+                start_price,
+                step_size,
+                step_interval,
+            )?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Encerra o tempo de espera de cruzamento de um Kitty do chamador, que paga
+        /// `AccelerateFee` a `FeeResolver`.
+        #[pallet::call_index(33)]
+        #[pallet::weight(10_000)]
+        pub fn accelerate_cooldown(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_accelerate_cooldown(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Queima dois Kitties do chamador e cria um novo com DNA combinado dos dois, reduzindo
+        /// a oferta em um.
+        #[pallet::call_index(34)]
+        #[pallet::weight(10_000)]
+        pub fn merge(origin: OriginFor<T>, kitty_a: DnaOf<T>, kitty_b: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_merge(who.clone(), kitty_a, kitty_b)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Equipa num Kitty do chamador um item que ele possui, substituindo o item anterior.
+        #[pallet::call_index(35)]
+        #[pallet::weight(10_000)]
+        pub fn equip(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_equip(who.clone(), kitty_id, item_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Remove o item equipado num Kitty do chamador.
+        #[pallet::call_index(36)]
+        #[pallet::weight(10_000)]
+        pub fn unequip(origin: OriginFor<T>, kitty_id: DnaOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_unequip(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Define à força o dono de um Kitty, reparando a posse do dono anterior, do novo dono
+        /// e de qualquer conta que o listasse por engano. Apenas a origem root pode chamar.
+        #[pallet::call_index(37)]
+        #[pallet::weight(10_000)]
+        pub fn force_set_owner(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_force_set_owner(kitty_id, new_owner)?;
+            Ok(())
+        }
+
+        /// Transfere um Kitty do chamador para `to` e já o anuncia por `new_price`, para
+        /// negociações combinadas fora do mercado.
+        ///
+        /// Diferente de `transfer`, o anúncio não é descartado: o novo dono o herda, conta em
+        /// `MaxListingsPerAccount` dele e respeita `PriceUpdateCooldown`. Emite `Transferred` e
+        /// depois `PriceSet`.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn transfer_with_price(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            kitty_id: DnaOf<T>,
+            new_price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_transfer_with_price(who.clone(), to, kitty_id, new_price)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Devolve e remove todas as ofertas por um Kitty do chamador, emitindo um
+        /// `OfferRejected` por comprador.
+        ///
+        /// O peso cobra `MaxOffersPerKitty` ofertas e devolve a diferença conforme as ofertas
+        /// realmente devolvidas.
+        #[pallet::call_index(39)]
+        #[pallet::weight(Pallet::<T>::batch_weight(T::MaxOffersPerKitty::get()))]
+        pub fn clear_offers(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let refunded = Self::do_clear_offers(who.clone(), kitty_id)?;
+            Self::note_activity(&who);
+            Ok(Some(Self::batch_weight(refunded)).into())
+        }
+
+        /// Apaga o registro de atividade do chamador. Só é aceito quando o chamador não possui
+        /// nenhum Kitty; o limite de posse e o nonce de criação ficam.
+        #[pallet::call_index(40)]
+        #[pallet::weight(10_000)]
+        pub fn purge_account(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_purge_account(who)?;
+            Ok(())
+        }
+
+        /// Compra os anúncios mais baratos, em ordem crescente de preço, até comprar
+        /// `max_count` Kitties ou até a próxima compra passar de `max_total_spend` no total.
+        ///
+        /// Examina apenas os primeiros `MaxScan` Kitties, pula os do próprio chamador e os que
+        /// ele não conseguiria comprar, e compra no máximo `MaxBatchSize` por chamada. As
+        /// compras nunca deixam o chamador abaixo do depósito existencial. A primeira compra que
+        /// falhar, por saldo ou limite de posse, encerra a varredura sem desfazer as anteriores.
+        #[pallet::call_index(41)]
+        #[pallet::weight(Pallet::<T>::sweep_weight((*max_count).min(T::MaxBatchSize::get())))]
+        pub fn sweep(
+            origin: OriginFor<T>,
+            max_total_spend: BalanceOf<T>,
+            max_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let attempted = Self::do_sweep(who.clone(), max_total_spend, max_count)?;
+            Self::note_activity(&who);
+            Ok(Some(Self::sweep_weight(attempted)).into())
+        }
+
+        /// Transfere `amount` das taxas acumuladas em `fee_account` para `to`. Apenas a
+        /// origem root.
+        #[pallet::call_index(42)]
+        #[pallet::weight(10_000)]
+        pub fn withdraw_fees(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_withdraw_fees(to, amount)?;
+            Ok(())
+        }
+
+        /// Reatribui a `new_owner` um Kitty cujo dono foi removido da cadeia, por exemplo por
+        /// ficar com saldo abaixo do depósito existencial. Apenas a origem root pode chamar.
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn rehome(
+            origin: OriginFor<T>,
+            kitty_id: DnaOf<T>,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_rehome(kitty_id, new_owner)?;
+            Ok(())
+        }
+
+        /// Transfere um Kitty do chamador para a conta derivada de `parent` e `index`, como os
+        /// endereços de depósito por usuário das corretoras. Veja `derived_account`.
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn transfer_to_derived(
+            origin: OriginFor<T>,
+            parent: T::AccountId,
+            index: u16,
+            kitty_id: DnaOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_transfer_to_derived(who.clone(), parent, index, kitty_id)?;
+            Self::note_activity(&who);
+            Ok(())
+        }
+
+        /// Transfere para `to` um Kitty da conta derivada do chamador com `index`, para que os
+        /// Kitties enviados com `transfer_to_derived` não fiquem presos nela.
+        #[pallet::call_index(45)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn transfer_from_derived(
+            origin: OriginFor<T>,
+            index: u16,
+            kitty_id: DnaOf<T>,
+            to: T::AccountId,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            // Implement buy_kitty logic here
+            Self::do_transfer_from_derived(who.clone(), index, kitty_id, to)?;
+            Self::note_activity(&who);
             Ok(())
         }
     }
-}
\ No newline at end of file
+}
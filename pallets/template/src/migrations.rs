@@ -0,0 +1,185 @@
+//! Migrações de armazenamento do pallet.
+//!
+//! Os formatos antigos guardam o DNA como `DnaOf<T>`: foram gravados como `[u8; 32]`, que tem a
+//! mesma codificação com `DnaLength` de 32 bytes, o único tamanho anterior a `DnaLength`.
+
+use super::*;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+
+const LOG_TARGET: &str = "runtime::kitties";
+
+/// Versão 1: adiciona `format_version` a cada Kitty.
+pub mod v1 {
+    use super::*;
+
+    /// `Kitty` como era gravado antes de `format_version`.
+    #[derive(Encode, Decode)]
+    pub struct OldKitty<T: Config> {
+        pub dna: DnaOf<T>,
+        pub owner: T::AccountId,
+        pub price: Option<BalanceOf<T>>,
+        pub species: u8,
+        pub cooldown_until: BlockNumberFor<T>,
+        pub level: u8,
+        pub last_fed: BlockNumberFor<T>,
+        pub transfer_locked_until: Option<BlockNumberFor<T>>,
+    }
+
+    /// `Kitty` como gravado pela versão 1, antes de `equipped`.
+    #[derive(Encode, Decode)]
+    pub struct KittyV1<T: Config> {
+        pub dna: DnaOf<T>,
+        pub owner: T::AccountId,
+        pub price: Option<BalanceOf<T>>,
+        pub species: u8,
+        pub cooldown_until: BlockNumberFor<T>,
+        pub level: u8,
+        pub last_fed: BlockNumberFor<T>,
+        pub transfer_locked_until: Option<BlockNumberFor<T>>,
+        pub format_version: u8,
+    }
+
+    /// `Kitties` com o formato da versão 1.
+    #[frame_support::storage_alias]
+    pub type Kitties<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, DnaOf<T>, KittyV1<T>>;
+
+    /// Grava `format_version = 1` em todos os Kitties existentes.
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            // Grava o formato da versão 1; `v3` o converte para o atual.
+            Kitties::<T>::translate::<OldKitty<T>, _>(|_, old| {
+                translated += 1;
+                Some(KittyV1 {
+                    dna: old.dna,
+                    owner: old.owner,
+                    price: old.price,
+                    species: old.species,
+                    cooldown_until: old.cooldown_until,
+                    level: old.level,
+                    last_fed: old.last_fed,
+                    transfer_locked_until: old.transfer_locked_until,
+                    // Fixo em 1, e não `KITTY_FORMAT_VERSION`, que muda com as próximas versões.
+                    format_version: 1,
+                })
+            });
+            log::info!(target: LOG_TARGET, "v1: {} Kitties receberam format_version", translated);
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Kitties::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let before =
+                u64::decode(&mut &state[..]).map_err(|_| "estado de pre_upgrade inválido")?;
+            let stamped = Kitties::<T>::iter_values().filter(|kitty| kitty.format_version == 1);
+            ensure!(stamped.count() as u64 == before, "nem todo Kitty foi migrado para a v1");
+            Ok(())
+        }
+    }
+
+    /// Executa `InnerMigrateV0ToV1` apenas quando o pallet está na versão 0 de armazenamento.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Versão 2: troca a lista de posse `KittiesOwned` por `OwnedKitties` e `OwnedCount`.
+pub mod v2 {
+    use super::*;
+
+    /// Lista de posse como era gravada até a versão 1.
+    #[frame_support::storage_alias]
+    pub type KittiesOwned<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        BoundedVec<DnaOf<T>, <T as Config>::MaxKittiesOwned>,
+        ValueQuery,
+    >;
+
+    /// Esvazia `KittiesOwned`, registrando cada Kitty em `OwnedKitties` e a quantidade em
+    /// `OwnedCount`. `OwnerCount` não muda, pois o conjunto de donos é o mesmo.
+    pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let (mut reads, mut writes) = (0u64, 0u64);
+            for (who, owned) in KittiesOwned::<T>::drain() {
+                reads += 1;
+                writes += 1;
+                if owned.is_empty() {
+                    continue;
+                }
+                OwnedCount::<T>::insert(&who, owned.len() as u32);
+                for kitty_id in owned.iter() {
+                    OwnedKitties::<T>::insert(&who, kitty_id, ());
+                }
+                writes += 1 + owned.len() as u64;
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+
+    /// Executa `InnerMigrateV1ToV2` apenas quando o pallet está na versão 1 de armazenamento.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Versão 3: adiciona `equipped` a cada Kitty.
+pub mod v3 {
+    use super::*;
+    use v1::KittyV1;
+
+    /// Grava `equipped = None` e `format_version = 2` em todos os Kitties existentes.
+    pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Kitties::<T>::translate::<KittyV1<T>, _>(|_, old| {
+                translated += 1;
+                Some(Kitty {
+                    dna: old.dna,
+                    owner: old.owner,
+                    price: old.price,
+                    species: old.species,
+                    cooldown_until: old.cooldown_until,
+                    level: old.level,
+                    last_fed: old.last_fed,
+                    transfer_locked_until: old.transfer_locked_until,
+                    format_version: 2,
+                    equipped: None,
+                })
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Executa `InnerMigrateV2ToV3` apenas quando o pallet está na versão 2 de armazenamento.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,14 +1,31 @@
 use crate as pallet_template;
-use frame_support::derive_impl;
-use sp_runtime::BuildStorage;
+use crate::{
+	DnaFilter, DnaOf, EvenOddSplit, Inheritance, InspectItems, OnKittyReceived, OnKittySold,
+	OnNewKitty, PrintableAscii, TraitMixer, TransferValidator,
+};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{tokens::Preservation, ConstU32, ConstU64, ConstU8, Hooks},
+	PalletId,
+};
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult, Permill};
 
 type Block = frame_system::mocking::MockBlock<Test>;
+type Balance = u64;
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+
+/// Saldo inicial de cada conta de teste.
+pub const INITIAL_BALANCE: Balance = 1_000;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		TemplateModule: pallet_template,
 	}
 );
@@ -16,14 +33,199 @@ frame_support::construct_runtime!(
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
 	type Block = Block;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type Balance = Balance;
+}
+
+parameter_types! {
+	/// Valor de `ListingDecay`, alternado pelos testes.
+	pub static ListingDecay: Permill = Permill::from_percent(10);
+	pub const KittiesPalletId: PalletId = PalletId(*b"py/kitty");
+	/// Criações registradas por `RecordMint`.
+	pub static MintedKitties: Vec<(u64, DnaOf<Test>)> = vec![];
+	/// Vendas registradas por `RecordSale`, como `(comprador, vendedor, kitty_id, preço)`.
+	pub static SoldKitties: Vec<(u64, u64, DnaOf<Test>, u64)> = vec![];
+	/// Valor de `EmitPerItemEvents`, alternado pelos testes.
+	pub static EmitPerItemEvents: bool = true;
+	/// Valor de `MaxListingsPerAccount`, alternado pelos testes.
+	pub static MaxListingsPerAccount: u32 = 10;
+	/// Valor de `BuyPreservation`, alternado pelos testes.
+	pub static BuyPreservation: Preservation = Preservation::Preserve;
+	/// Contas que `RejectSome` faz recusar Kitties recebidos.
+	pub static RejectingAccounts: Vec<u64> = vec![];
+	/// Valor de `MaxSupply`, alternado pelos testes.
+	pub static MaxSupply: u32 = u32::MAX;
+	/// Valor de `MintDeposit`, alternado pelos testes.
+	pub static MintDeposit: Balance = 0;
+	pub const BurnRefundRatio: Permill = Permill::from_percent(80);
+	pub const AccelerateFee: Balance = 30;
+	/// Liga a regra fixa de `FixedTraitsMixer`, alternado pelos testes.
+	pub static FixedTraits: bool = false;
+	/// Valor de `MaxGeneration`, alternado pelos testes.
+	pub static MaxGeneration: u16 = u16::MAX;
+	/// Valor de `AllowOpenMint`, alternado pelos testes.
+	pub static AllowOpenMint: bool = true;
+	/// Itens existentes e os seus donos, lidos por `MockItems`.
+	pub static ItemOwners: Vec<(u32, u64)> = vec![];
+	/// Valor de `MaxOffersPerKitty`, alternado pelos testes.
+	pub static MaxOffersPerKitty: u32 = 16;
+	/// Valor de `PriceUpdateCooldown`, alternado pelos testes.
+	pub static PriceUpdateCooldown: u64 = 0;
+	/// DNAs que `BlockDna` recusa.
+	pub static BlockedDna: Vec<DnaOf<Test>> = vec![];
+	/// Valor de `MinSireFee`, alternado pelos testes.
+	pub static MinSireFee: Balance = 0;
+	/// Conta para a qual `BlockRecipient` recusa transferências.
+	pub static BlockedRecipient: Option<u64> = None;
+	/// Valor de `DnaLength`, alternado pelos testes.
+	pub static DnaLength: u32 = 32;
+}
+
+/// Registra cada Kitty criado em `MintedKitties`.
+pub struct RecordMint;
+impl OnNewKitty<u64> for RecordMint {
+	fn on_mint(owner: &u64, dna: &[u8]) {
+		MintedKitties::mutate(|minted| minted.push((*owner, dna_of(dna))));
+	}
+}
+
+/// Converte os bytes recebidos por um hook de volta em `DnaOf<Test>`.
+fn dna_of(bytes: &[u8]) -> DnaOf<Test> {
+	DnaOf::<Test>::try_from(bytes.to_vec()).expect("o pallet só gera DNAs de `DnaLength` bytes")
+}
+
+/// Com `FixedTraits` ligado, herda espécie e gênero do primeiro pai, a cor do segundo e muta o
+/// padrão para listrado; os demais bytes seguem `EvenOddSplit`.
+pub struct FixedTraitsMixer;
+impl TraitMixer for FixedTraitsMixer {
+	fn inherit(index: usize, seed: u8) -> Inheritance {
+		match index {
+			0..=1 if FixedTraits::get() => Inheritance::First,
+			2..=4 if FixedTraits::get() => Inheritance::Second,
+			5 if FixedTraits::get() => Inheritance::Mutation(1),
+			_ => EvenOddSplit::inherit(index, seed),
+		}
+	}
+}
+
+/// Recusa os DNAs listados em `BlockedDna`.
+pub struct BlockDna;
+impl DnaFilter for BlockDna {
+	fn is_allowed(dna: &[u8]) -> bool {
+		!BlockedDna::get().iter().any(|blocked| blocked[..] == *dna)
+	}
+}
+
+/// Itens de um pallet fictício, com a posse guardada em `ItemOwners`.
+pub struct MockItems;
+impl InspectItems<u64, u32> for MockItems {
+	fn owner_of(item: &u32) -> Option<u64> {
+		ItemOwners::get().into_iter().find(|(id, _)| id == item).map(|(_, owner)| owner)
+	}
+}
+
+/// Recusa transferências para as contas em `RejectingAccounts`.
+pub struct RejectSome;
+impl OnKittyReceived<u64> for RejectSome {
+	fn on_received(_from: &u64, to: &u64, _kitty_id: &[u8]) -> DispatchResult {
+		if RejectingAccounts::get().contains(to) {
+			return Err(DispatchError::Other("recipient rejected the Kitty"));
+		}
+		Ok(())
+	}
+}
+
+/// Recusa transferências para `BlockedRecipient`, como uma lista de permitidos faria.
+pub struct BlockRecipient;
+impl TransferValidator<u64> for BlockRecipient {
+	fn validate(_from: &u64, to: &u64, _kitty_id: &[u8]) -> DispatchResult {
+		if BlockedRecipient::get() == Some(*to) {
+			return Err(DispatchError::Other("recipient is not allowlisted"));
+		}
+		Ok(())
+	}
+}
+
+/// Registra cada venda em `SoldKitties`.
+pub struct RecordSale;
+impl OnKittySold<u64, u64> for RecordSale {
+	fn on_sale(buyer: &u64, seller: &u64, kitty_id: &[u8], price: u64) {
+		SoldKitties::mutate(|sold| sold.push((*buyer, *seller, dna_of(kitty_id), price)));
+	}
 }
 
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type NativeBalance = Balances;
+	type MaxKittiesOwned = ConstU32<10>;
+	type BreedingCooldown = ConstU64<5>;
+	type ListingDecay = ListingDecay;
+	type MaxDecaysPerBlock = ConstU32<2>;
+	type MintDifficulty = ConstU8<1>;
+	type OnMint = RecordMint;
+	type OnSale = RecordSale;
+	type MaxBatchSize = ConstU32<5>;
+	type EmitPerItemEvents = EmitPerItemEvents;
 	type WeightInfo = ();
+	type MaxNameLength = ConstU32<8>;
+	type NamePolicy = PrintableAscii;
+	type MaxMemo = ConstU32<16>;
+	type MaxScan = ConstU32<100>;
+	type BurnDelay = ConstU64<3>;
+	type FeedCooldown = ConstU64<2>;
+	type MaxListingsPerAccount = MaxListingsPerAccount;
+	type PalletId = KittiesPalletId;
+	type BuyPreservation = BuyPreservation;
+	type InactivityThreshold = ConstU64<10>;
+	type OnReceive = RejectSome;
+	type MaxSupply = MaxSupply;
+	type MintDeposit = MintDeposit;
+	type BurnRefundRatio = BurnRefundRatio;
+	type FeeResolver = crate::PalletFeeAccount<Test>;
+	type AccelerateFee = AccelerateFee;
+	type TraitInheritance = FixedTraitsMixer;
+	type MaxGeneration = MaxGeneration;
+	type AllowOpenMint = AllowOpenMint;
+	type ItemId = u32;
+	type ItemsInspect = MockItems;
+	type MaxOffersPerKitty = MaxOffersPerKitty;
+	type MaxPricePoints = ConstU32<3>;
+	type PriceUpdateCooldown = PriceUpdateCooldown;
+	type DnaFilter = BlockDna;
+	type MinSireFee = MinSireFee;
+	type TransferValidator = BlockRecipient;
+	type DnaLength = DnaLength;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(ALICE, INITIAL_BALANCE),
+			(BOB, INITIAL_BALANCE),
+			(CHARLIE, INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = t.into();
+	// Go past genesis block so events get deposited
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Avança até o bloco `n`, executando `on_initialize` do pallet em cada bloco.
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		TemplateModule::on_initialize(next);
+	}
 }
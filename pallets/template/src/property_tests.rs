@@ -0,0 +1,91 @@
+//! Testes de propriedade: sequências aleatórias (com semente fixa) de criação, anúncio,
+//! transferência e queima, verificando os invariantes de armazenamento após cada passo.
+
+use crate::{mock::*, DnaOf, Kitties};
+use frame_support::assert_ok;
+
+/// Gerador xorshift64, determinístico para uma mesma semente.
+struct Rng(u64);
+
+impl Rng {
+	fn next(&mut self) -> u64 {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 7;
+		self.0 ^= self.0 << 17;
+		self.0
+	}
+
+	fn below(&mut self, n: u64) -> u64 {
+		self.next() % n
+	}
+
+	fn account(&mut self) -> u64 {
+		[ALICE, BOB, CHARLIE][self.below(3) as usize]
+	}
+
+	/// Escolhe um Kitty de `who`, se ele tiver algum.
+	fn owned_by(&mut self, who: u64) -> Option<DnaOf<Test>> {
+		let owned = TemplateModule::owned_ids(&who);
+		(!owned.is_empty()).then(|| owned[self.below(owned.len() as u64) as usize].clone())
+	}
+}
+
+fn run_sequence(seed: u64, steps: u64) {
+	new_test_ext().execute_with(|| {
+		let mut rng = Rng(seed);
+
+		for step in 1..=steps {
+			// Cada passo em um bloco novo, para que os DNAs gerados sejam distintos.
+			System::set_block_number(step);
+			let who = rng.account();
+
+			match rng.below(4) {
+				0 => {
+					let _ = TemplateModule::create_kitty(RuntimeOrigin::signed(who), None);
+				},
+				1 =>
+					if let Some(kitty_id) = rng.owned_by(who) {
+						let price = rng.below(1_000) + 1;
+						assert_ok!(TemplateModule::set_price(
+							RuntimeOrigin::signed(who),
+							kitty_id,
+							Some(price),
+							None
+						));
+					},
+				2 =>
+					if let Some(kitty_id) = rng.owned_by(who) {
+						let to = rng.account();
+						let result = TemplateModule::transfer(
+							RuntimeOrigin::signed(who),
+							to,
+							kitty_id.clone(),
+							None,
+						);
+						if result.is_ok() {
+							let kitty = Kitties::<Test>::get(&kitty_id).unwrap();
+							assert_eq!(kitty.owner, to);
+							assert_eq!(kitty.price, None, "transferência manteve o preço antigo");
+						}
+					},
+				_ =>
+					if let Some(kitty_id) = rng.owned_by(who) {
+						assert_ok!(TemplateModule::burn_kitty(
+							RuntimeOrigin::signed(who),
+							kitty_id.clone()
+						));
+						assert!(!Kitties::<Test>::contains_key(&kitty_id));
+					},
+			}
+
+			assert_ok!(TemplateModule::do_try_state());
+		}
+	});
+}
+
+#[test]
+fn random_operations_preserve_invariants() {
+	for seed in [1, 42, 0xdead_beef, 0x1234_5678_9abc_def0] {
+		run_sequence(seed, 300);
+	}
+}
@@ -0,0 +1,96 @@
+//! API de runtime com consultas somente leitura sobre os Kitties.
+
+use crate::{BreedError, BuyError, ConfigConstants, KittyDetails, KittyTraits, MarketSummary};
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Consultas sobre os Kitties para carteiras e interfaces. `KittyId` é o DNA dos Kitties,
+    /// `DnaOf` do runtime.
+    pub trait KittiesApi<AccountId, Balance, BlockNumber, KittyId>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        KittyId: Codec,
+    {
+        /// Retorna até `limit` Kitties da espécie `species`, pulando os `start` primeiros.
+        fn kitties_of_species(species: u8, start: u32, limit: u32) -> Vec<KittyId>;
+
+        /// Retorna quantos blocos faltam para o Kitty poder cruzar novamente, ou `None` se ele
+        /// já pode cruzar ou não existe.
+        fn cooldown_remaining(kitty_id: KittyId) -> Option<BlockNumber>;
+
+        /// Verifica se `buyer` conseguiria comprar o Kitty agora, retornando o preço que pagaria
+        /// ou o motivo da falha.
+        fn can_buy(buyer: AccountId, kitty_id: KittyId) -> Result<Balance, BuyError>;
+
+        /// Verifica se `who` conseguiria cruzar os dois Kitties agora, retornando o motivo da
+        /// falha.
+        fn can_breed(
+            parent_1: KittyId,
+            parent_2: KittyId,
+            who: AccountId,
+        ) -> Result<(), BreedError>;
+
+        /// Retorna quantos Kitties a conta possui e quantos ela pode possuir.
+        fn owned_capacity(account: AccountId) -> (u32, u32);
+
+        /// Retorna quantos Kitties a conta ainda pode receber.
+        fn recipient_remaining_slots(to: AccountId) -> u32;
+
+        /// Retorna o `index`-ésimo Kitty de `owner`, na ordem de armazenamento, se existir.
+        fn kitty_of_owner_by_index(owner: AccountId, index: u32) -> Option<KittyId>;
+
+        /// Retorna o Kitty à venda mais barato e o seu preço, entre os primeiros `MaxScan`.
+        fn floor_listing() -> Option<(KittyId, Balance)>;
+
+        /// Retorna os Kitties da conta, na ordem de armazenamento.
+        fn owned_ids(account: AccountId) -> Vec<KittyId>;
+
+        /// Retorna a soma dos preços dos Kitties anunciados pela conta.
+        fn listed_value(account: AccountId) -> Balance;
+
+        /// Retorna até `limit` Kitties anunciados por `owner`, com os seus preços, pulando os
+        /// `start` primeiros.
+        fn listings_of(owner: AccountId, start: u32, limit: u32) -> Vec<(KittyId, Balance)>;
+
+        /// Retorna o bloco e o preço das últimas vendas do Kitty, da mais antiga para a mais
+        /// recente.
+        fn price_history(kitty_id: KittyId) -> Vec<(BlockNumber, Balance)>;
+
+        /// Retorna a soma dos valores guardados nas ofertas em aberto da conta.
+        fn total_reserved_offers(who: AccountId) -> Balance;
+
+        /// Retorna uma página de todos os Kitties com os seus dados, começando depois de
+        /// `start`, e o cursor da próxima página, para indexação em massa.
+        fn snapshot(
+            start: Option<KittyId>,
+            limit: u32,
+        ) -> (
+            Vec<(KittyId, KittyDetails<KittyId, AccountId, Balance, BlockNumber>)>,
+            Option<KittyId>,
+        );
+
+        /// Retorna estatísticas agregadas do mercado.
+        fn market_summary() -> MarketSummary<Balance>;
+
+        /// Retorna o preço anunciado do Kitty, ou zero se ele não está à venda ou não existe.
+        fn price_or_zero(kitty_id: KittyId) -> Balance;
+
+        /// Retorna o nome do Kitty, ou um nome vazio se ele não tem nome ou não existe.
+        fn name_or_empty(kitty_id: KittyId) -> Vec<u8>;
+
+        /// Prevê o DNA e as características do filho de dois Kitties, sem cruzá-los. O
+        /// cruzamento real só terá o mesmo resultado se entrar no mesmo bloco, na mesma
+        /// posição.
+        fn dry_run_breed(parent_1: KittyId, parent_2: KittyId) -> Option<(KittyId, KittyTraits)>;
+
+        /// Retorna os limites e taxas configurados, para que as interfaces não os fixem no
+        /// código.
+        fn config_constants() -> ConfigConstants<Balance, BlockNumber>;
+
+        /// Retorna a conta derivada de `parent` e `index`, destino de `transfer_to_derived`.
+        fn derived_account(parent: AccountId, index: u16) -> AccountId;
+    }
+}
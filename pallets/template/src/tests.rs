@@ -1,27 +1,4096 @@
-use crate::{mock::*, Error, Event, Something};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	color_of, decode_traits, gender_of, mock::*, pattern_of, species_of, Approvals, BreedError,
+	BuyError, ConfigConstants, CountForKitties, Dna, DnaOf, Error, Event, Gender, Generations,
+	Kitties, Kitty, KittyDetailsOf, KittyNames, KittyTraits, KITTY_FORMAT_VERSION, LastActivity,
+	LastSalePrice, ListingCount, MAX_LEVEL, MarketSummary, MintNonce, OfferCount, OfferedTotal,
+	Offers, OwnedCount, OwnedKitties, Pattern, PendingBurns, PrivateSales, Reservations,
+	SireListings, SpeciesIndex, TotalBurned, TransferReason, WeightInfo,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{GetDispatchInfo, Pays},
+	traits::{tokens::Preservation, Get},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_runtime::{traits::TrailingZeroInput, DispatchError, Permill};
+
+/// Cria um Kitty para `who` e retorna o seu DNA.
+fn create_kitty(who: u64) -> DnaOf<Test> {
+	assert_ok!(TemplateModule::create_kitty(RuntimeOrigin::signed(who), None));
+	MintedKitties::get().pop().unwrap().1
+}
+
+/// DNA com todos os bytes iguais a `byte`.
+fn filled_dna(byte: u8) -> DnaOf<Test> {
+	Dna::from_fn(|_| byte)
+}
+
+/// Coloca o Kitty de `who` à venda por `price`.
+fn list_kitty(who: u64, kitty_id: &DnaOf<Test>, price: u64) {
+	assert_ok!(TemplateModule::set_price(
+		RuntimeOrigin::signed(who),
+		kitty_id.clone(),
+		Some(price),
+		None
+	));
+}
+
+#[test]
+fn breed_kitty_mints_child_to_owner() {
+	new_test_ext().execute_with(|| {
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(ALICE);
+
+		assert_ok!(TemplateModule::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			parent_1.clone(),
+			parent_2.clone(),
+			None
+		));
+
+		assert_eq!(OwnedCount::<Test>::get(ALICE), 3);
+		let child = MintedKitties::get().pop().unwrap().1;
+		assert_eq!(Kitties::<Test>::get(&child).unwrap().owner, ALICE);
+		System::assert_last_event(
+			Event::Bred { owner: ALICE, kitty_id: child, parents: [parent_1, parent_2] }.into(),
+		);
+	});
+}
+
+#[test]
+fn closed_mint_still_allows_breeding_and_root_creation() {
+	new_test_ext().execute_with(|| {
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(ALICE);
+		AllowOpenMint::set(false);
+
+		assert_noop!(
+			TemplateModule::create_kitty(RuntimeOrigin::signed(ALICE), None),
+			Error::<Test>::OpenMintDisabled
+		);
+		assert_noop!(
+			TemplateModule::create_kitty_pow(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::OpenMintDisabled
+		);
+
+		assert_ok!(TemplateModule::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			parent_1,
+			parent_2,
+			None
+		));
+		assert_ok!(TemplateModule::create_kitty(RuntimeOrigin::root(), Some(BOB)));
+		assert_eq!(OwnedCount::<Test>::get(ALICE), 3);
+		assert_eq!(OwnedCount::<Test>::get(BOB), 1);
+	});
+}
+
+#[test]
+fn breed_kitty_respects_cooldown() {
+	new_test_ext().execute_with(|| {
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(ALICE);
+		assert_ok!(TemplateModule::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			parent_1.clone(),
+			parent_2.clone(),
+			None
+		));
+
+		assert_noop!(
+			TemplateModule::breed_kitty(
+				RuntimeOrigin::signed(ALICE),
+				parent_1.clone(),
+				parent_2.clone(),
+				None
+			),
+			Error::<Test>::KittyOnCooldown
+		);
+
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			parent_1,
+			parent_2,
+			None
+		));
+	});
+}
+
+#[test]
+fn accelerate_cooldown_allows_breeding_immediately() {
+	new_test_ext().execute_with(|| {
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(ALICE);
+		assert_ok!(TemplateModule::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			parent_1.clone(),
+			parent_2.clone(),
+			None
+		));
+		let balance = Balances::free_balance(ALICE);
+
+		assert_ok!(TemplateModule::accelerate_cooldown(
+			RuntimeOrigin::signed(ALICE),
+			parent_1.clone()
+		));
+		assert_ok!(TemplateModule::accelerate_cooldown(
+			RuntimeOrigin::signed(ALICE),
+			parent_2.clone()
+		));
+		System::assert_last_event(
+			Event::CooldownAccelerated { owner: ALICE, kitty_id: parent_2.clone(), fee: 30 }.into(),
+		);
+		assert_eq!(Balances::free_balance(ALICE), balance - 60);
+		assert_eq!(Balances::free_balance(TemplateModule::fee_account()), 60);
+
+		assert_ok!(TemplateModule::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			parent_1,
+			parent_2,
+			None
+		));
+	});
+}
+
+#[test]
+fn accelerate_cooldown_checks_owner_and_cooldown() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_noop!(
+			TemplateModule::accelerate_cooldown(RuntimeOrigin::signed(ALICE), kitty_id.clone()),
+			Error::<Test>::NotOnCooldown
+		);
+
+		let other = create_kitty(ALICE);
+		assert_ok!(TemplateModule::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			other,
+			None
+		));
+		assert_noop!(
+			TemplateModule::accelerate_cooldown(RuntimeOrigin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn equip_and_unequip_owned_item() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		ItemOwners::set(vec![(1, ALICE), (2, ALICE)]);
+
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_id.clone(), 1));
+		System::assert_last_event(
+			Event::Equipped { owner: ALICE, kitty_id: kitty_id.clone(), item_id: 1 }.into(),
+		);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().equipped, Some(1));
+
+		// Equipar outro item substitui o anterior.
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_id.clone(), 2));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().equipped, Some(2));
+
+		assert_ok!(TemplateModule::unequip(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+		System::assert_last_event(
+			Event::Unequipped { owner: ALICE, kitty_id: kitty_id.clone(), item_id: 2 }.into(),
+		);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().equipped, None);
+		assert_noop!(
+			TemplateModule::unequip(RuntimeOrigin::signed(ALICE), kitty_id),
+			Error::<Test>::NothingEquipped
+		);
+	});
+}
+
+#[test]
+fn equip_requires_owning_kitty_and_item() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		ItemOwners::set(vec![(1, ALICE), (2, BOB)]);
+
+		assert_noop!(
+			TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_id.clone(), 2),
+			Error::<Test>::NotItemOwner
+		);
+		assert_noop!(
+			TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_id.clone(), 3),
+			Error::<Test>::NotItemOwner
+		);
+		assert_noop!(
+			TemplateModule::equip(RuntimeOrigin::signed(BOB), kitty_id.clone(), 2),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_id.clone(), 1));
+		assert_noop!(
+			TemplateModule::unequip(RuntimeOrigin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn item_can_be_equipped_on_one_kitty_only() {
+	new_test_ext().execute_with(|| {
+		let kitty_a = create_kitty(ALICE);
+		let kitty_b = create_kitty(ALICE);
+		ItemOwners::set(vec![(1, ALICE), (2, ALICE)]);
+
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_a.clone(), 1));
+		assert_noop!(
+			TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_b.clone(), 1),
+			Error::<Test>::ItemAlreadyEquipped
+		);
+		// Equipar de novo no mesmo Kitty não muda nada.
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_a.clone(), 1));
+
+		// Trocar o item de `kitty_a` libera o item 1 para `kitty_b`.
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_a.clone(), 2));
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_b.clone(), 1));
+		assert_eq!(Kitties::<Test>::get(&kitty_a).unwrap().equipped, Some(2));
+		assert_eq!(Kitties::<Test>::get(&kitty_b).unwrap().equipped, Some(1));
+		assert_ok!(TemplateModule::do_try_state());
+
+		// Queimar o Kitty também libera o item.
+		assert_ok!(TemplateModule::burn_kitty(RuntimeOrigin::signed(ALICE), kitty_b));
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_a, 1));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn changing_owner_unequips_the_item() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		let other = create_kitty(ALICE);
+		ItemOwners::set(vec![(1, ALICE), (2, ALICE)]);
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), kitty_id.clone(), 1));
+		list_kitty(ALICE, &kitty_id, 100);
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			100,
+			true,
+			None
+		));
+		System::assert_has_event(
+			Event::Unequipped { owner: ALICE, kitty_id: kitty_id.clone(), item_id: 1 }.into(),
+		);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().equipped, None);
+		// O item ficou com ALICE, que pode equipá-lo em outro Kitty.
+		assert_ok!(TemplateModule::equip(RuntimeOrigin::signed(ALICE), other.clone(), 1));
+
+		assert_ok!(TemplateModule::force_set_owner(RuntimeOrigin::root(), other.clone(), BOB));
+		System::assert_has_event(
+			Event::Unequipped { owner: ALICE, kitty_id: other.clone(), item_id: 1 }.into(),
+		);
+		assert_eq!(Kitties::<Test>::get(&other).unwrap().equipped, None);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn merge_burns_both_and_mints_one() {
+	new_test_ext().execute_with(|| {
+		let kitty_a = create_kitty(ALICE);
+		let kitty_b = create_kitty(ALICE);
+		create_kitty(BOB);
+		assert_eq!(CountForKitties::<Test>::get(), 3);
+
+		assert_ok!(TemplateModule::merge(
+			RuntimeOrigin::signed(ALICE),
+			kitty_a.clone(),
+			kitty_b.clone()
+		));
+
+		let created = MintedKitties::get().pop().unwrap().1;
+		System::assert_last_event(
+			Event::Merged { burned: [kitty_a.clone(), kitty_b.clone()], created: created.clone() }
+				.into(),
+		);
+		assert_eq!(CountForKitties::<Test>::get(), 2);
+		assert_eq!(TotalBurned::<Test>::get(), 2);
+		assert!(!Kitties::<Test>::contains_key(&kitty_a));
+		assert!(!Kitties::<Test>::contains_key(&kitty_b));
+		assert_eq!(Kitties::<Test>::get(&created).unwrap().owner, ALICE);
+		assert_eq!(TemplateModule::owned_ids(&ALICE), vec![created]);
+	});
+}
+
+#[test]
+fn merge_requires_two_owned_kitties() {
+	new_test_ext().execute_with(|| {
+		let kitty_a = create_kitty(ALICE);
+		let kitty_b = create_kitty(BOB);
+
+		assert_noop!(
+			TemplateModule::merge(RuntimeOrigin::signed(ALICE), kitty_a.clone(), kitty_b),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			TemplateModule::merge(RuntimeOrigin::signed(ALICE), kitty_a.clone(), kitty_a.clone()),
+			Error::<Test>::SameParents
+		);
+		assert_noop!(
+			TemplateModule::merge(RuntimeOrigin::signed(ALICE), kitty_a, filled_dna(0)),
+			Error::<Test>::NoKitty
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_follows_configured_trait_inheritance() {
+	new_test_ext().execute_with(|| {
+		FixedTraits::set(true);
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(ALICE);
+
+		assert_ok!(TemplateModule::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			parent_1.clone(),
+			parent_2.clone(),
+			None
+		));
+
+		let child = decode_traits(&MintedKitties::get().pop().unwrap().1);
+		assert_eq!(child.species, species_of(&parent_1));
+		assert_eq!(child.gender, gender_of(&parent_1));
+		assert_eq!(child.color, color_of(&parent_2));
+		assert_eq!(child.pattern, Pattern::Striped);
+	});
+}
+
+/// Cruza dois Kitties de `who` e retorna o DNA do filho.
+fn breed(who: u64, parent_1: &DnaOf<Test>, parent_2: &DnaOf<Test>) -> DnaOf<Test> {
+	assert_ok!(TemplateModule::breed_kitty(
+		RuntimeOrigin::signed(who),
+		parent_1.clone(),
+		parent_2.clone(),
+		None
+	));
+	MintedKitties::get().pop().unwrap().1
+}
+
+#[test]
+fn dry_run_breed_matches_breed_in_the_same_block() {
+	new_test_ext().execute_with(|| {
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(ALICE);
+		assert_eq!(TemplateModule::dry_run_breed(&parent_1, &parent_1), None);
+		assert_eq!(TemplateModule::dry_run_breed(&parent_1, &filled_dna(0)), None);
+
+		let (dna, traits) = TemplateModule::dry_run_breed(&parent_1, &parent_2).unwrap();
+		assert_eq!(traits, decode_traits(&dna));
+		assert!(!Kitties::<Test>::contains_key(&dna));
+
+		assert_eq!(breed(ALICE, &parent_1, &parent_2), dna);
+	});
+}
+
+#[test]
+fn can_breed_reports_each_rejection_reason() {
+	new_test_ext().execute_with(|| {
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(ALICE);
+		let foreign = create_kitty(BOB);
+		let check = |kitty_a: &DnaOf<Test>, kitty_b: &DnaOf<Test>, who| {
+			TemplateModule::can_breed(kitty_a, kitty_b, &who)
+		};
+
+		assert_eq!(check(&parent_1, &parent_2, ALICE), Ok(()));
+		assert_eq!(check(&parent_1, &filled_dna(9), ALICE), Err(BreedError::NoKitty));
+		assert_eq!(check(&parent_1, &foreign, ALICE), Err(BreedError::DifferentOwners));
+		assert_eq!(check(&parent_1, &parent_2, BOB), Err(BreedError::NotAuthorized));
+		assert_eq!(check(&parent_1, &parent_1, ALICE), Err(BreedError::SameParents));
+
+		MaxGeneration::set(0);
+		assert_eq!(check(&parent_1, &parent_2, ALICE), Err(BreedError::MaxGenerationReached));
+		MaxGeneration::set(u16::MAX);
+
+		MaxSupply::set(3);
+		assert_eq!(check(&parent_1, &parent_2, ALICE), Err(BreedError::TooManyKitties));
+		MaxSupply::set(u32::MAX);
+
+		assert_ok!(TemplateModule::set_owned_limit(RuntimeOrigin::root(), ALICE, Some(2)));
+		assert_eq!(check(&parent_1, &parent_2, ALICE), Err(BreedError::TooManyOwned));
+		assert_ok!(TemplateModule::set_owned_limit(RuntimeOrigin::root(), ALICE, None));
+
+		breed(ALICE, &parent_1, &parent_2);
+		assert_eq!(check(&parent_1, &parent_2, ALICE), Err(BreedError::OnCooldown));
+	});
+}
+
+#[test]
+fn breed_kitty_stops_at_max_generation() {
+	new_test_ext().execute_with(|| {
+		MaxGeneration::set(2);
+		let first = breed(ALICE, &create_kitty(ALICE), &create_kitty(ALICE));
+		let second = breed(ALICE, &create_kitty(ALICE), &create_kitty(ALICE));
+		assert_eq!(Generations::<Test>::get(&first), 1);
+
+		let last = breed(ALICE, &first, &second);
+		assert_eq!(Generations::<Test>::get(&last), 2);
+
+		// O pai mais novo decide a geração do filho.
+		let fresh = create_kitty(ALICE);
+		assert_eq!(Generations::<Test>::get(&fresh), 0);
+		assert_noop!(
+			TemplateModule::breed_kitty(RuntimeOrigin::signed(ALICE), last, fresh, None),
+			Error::<Test>::MaxGenerationReached
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_requires_owning_both_parents() {
+	new_test_ext().execute_with(|| {
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(BOB);
+
+		assert_noop!(
+			TemplateModule::breed_kitty(
+				RuntimeOrigin::signed(ALICE),
+				parent_1.clone(),
+				parent_2,
+				None
+			),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			TemplateModule::breed_kitty(
+				RuntimeOrigin::signed(ALICE),
+				parent_1.clone(),
+				parent_1,
+				None
+			),
+			Error::<Test>::SameParents
+		);
+	});
+}
+
+#[test]
+fn approved_account_breeds_for_the_owner() {
+	new_test_ext().execute_with(|| {
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(ALICE);
+		assert_ok!(TemplateModule::approve(
+			RuntimeOrigin::signed(ALICE),
+			parent_1.clone(),
+			Some(BOB)
+		));
+
+		// Autorizado em apenas um dos pais não basta.
+		assert_noop!(
+			TemplateModule::breed_kitty(
+				RuntimeOrigin::signed(BOB),
+				parent_1.clone(),
+				parent_2.clone(),
+				None
+			),
+			Error::<Test>::NotAuthorizedToBreed
+		);
+		assert_ok!(TemplateModule::approve(
+			RuntimeOrigin::signed(ALICE),
+			parent_2.clone(),
+			Some(BOB)
+		));
+		assert_noop!(
+			TemplateModule::breed_kitty(
+				RuntimeOrigin::signed(CHARLIE),
+				parent_1.clone(),
+				parent_2.clone(),
+				None
+			),
+			Error::<Test>::NotAuthorizedToBreed
+		);
+
+		let child = breed(BOB, &parent_1, &parent_2);
+		assert_eq!(Kitties::<Test>::get(&child).unwrap().owner, ALICE);
+		assert_eq!(OwnedCount::<Test>::get(ALICE), 3);
+		assert_eq!(OwnedCount::<Test>::get(BOB), 0);
+	});
+}
+
+#[test]
+fn breed_with_sire_pays_fee_and_mints_child_to_caller() {
+	new_test_ext().execute_with(|| {
+		let sire = create_kitty(ALICE);
+		let my_kitty = create_kitty(BOB);
+		assert_ok!(TemplateModule::offer_as_sire(
+			RuntimeOrigin::signed(ALICE),
+			sire.clone(),
+			Some(50)
+		));
+		assert_eq!(SireListings::<Test>::get(&sire), Some(50));
+
+		assert_ok!(TemplateModule::breed_with_sire(
+			RuntimeOrigin::signed(BOB),
+			my_kitty,
+			sire.clone()
+		));
+
+		// A taxa vai para o dono do reprodutor.
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 50);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 50);
+
+		// O filho pertence ao chamador e o reprodutor continua com o dono.
+		let child = MintedKitties::get().pop().unwrap().1;
+		assert_eq!(OwnedCount::<Test>::get(BOB), 2);
+		assert_eq!(Kitties::<Test>::get(&child).unwrap().owner, BOB);
+		assert_eq!(TemplateModule::owned_ids(&ALICE), vec![sire.clone()]);
+		assert_eq!(Kitties::<Test>::get(&sire).unwrap().owner, ALICE);
+	});
+}
+
+#[test]
+fn breed_with_sire_respects_cooldown_on_both() {
+	new_test_ext().execute_with(|| {
+		let sire = create_kitty(ALICE);
+		let kitty_1 = create_kitty(BOB);
+		let kitty_2 = create_kitty(CHARLIE);
+		assert_ok!(TemplateModule::offer_as_sire(
+			RuntimeOrigin::signed(ALICE),
+			sire.clone(),
+			Some(50)
+		));
+		assert_ok!(TemplateModule::breed_with_sire(
+			RuntimeOrigin::signed(BOB),
+			kitty_1.clone(),
+			sire.clone()
+		));
+
+		// O reprodutor está em espera.
+		assert_noop!(
+			TemplateModule::breed_with_sire(RuntimeOrigin::signed(CHARLIE), kitty_2, sire.clone()),
+			Error::<Test>::KittyOnCooldown
+		);
+
+		// O Kitty do chamador também está em espera.
+		System::set_block_number(6);
+		let other_sire = create_kitty(ALICE);
+		assert_ok!(TemplateModule::offer_as_sire(
+			RuntimeOrigin::signed(ALICE),
+			other_sire.clone(),
+			Some(50)
+		));
+		assert_ok!(TemplateModule::breed_with_sire(
+			RuntimeOrigin::signed(BOB),
+			kitty_1.clone(),
+			other_sire
+		));
+		assert_noop!(
+			TemplateModule::breed_with_sire(RuntimeOrigin::signed(BOB), kitty_1, sire),
+			Error::<Test>::KittyOnCooldown
+		);
+	});
+}
+
+#[test]
+fn breed_with_sire_requires_listing() {
+	new_test_ext().execute_with(|| {
+		let sire = create_kitty(ALICE);
+		let my_kitty = create_kitty(BOB);
+
+		assert_noop!(
+			TemplateModule::breed_with_sire(
+				RuntimeOrigin::signed(BOB),
+				my_kitty.clone(),
+				sire.clone()
+			),
+			Error::<Test>::NotSire
+		);
+		assert_noop!(
+			TemplateModule::offer_as_sire(RuntimeOrigin::signed(BOB), sire.clone(), Some(50)),
+			Error::<Test>::NotOwner
+		);
+
+		// Retirar a oferta impede novos cruzamentos.
+		assert_ok!(TemplateModule::offer_as_sire(
+			RuntimeOrigin::signed(ALICE),
+			sire.clone(),
+			Some(50)
+		));
+		assert_ok!(TemplateModule::offer_as_sire(RuntimeOrigin::signed(ALICE), sire.clone(), None));
+		assert_noop!(
+			TemplateModule::breed_with_sire(RuntimeOrigin::signed(BOB), my_kitty, sire),
+			Error::<Test>::NotSire
+		);
+	});
+}
+
+#[test]
+fn offer_as_sire_enforces_min_sire_fee() {
+	new_test_ext().execute_with(|| {
+		MinSireFee::set(50);
+		let sire = create_kitty(ALICE);
+
+		assert_noop!(
+			TemplateModule::offer_as_sire(RuntimeOrigin::signed(ALICE), sire.clone(), Some(49)),
+			Error::<Test>::SireFeeTooLow
+		);
+		assert_ok!(TemplateModule::offer_as_sire(
+			RuntimeOrigin::signed(ALICE),
+			sire.clone(),
+			Some(50)
+		));
+		assert_eq!(SireListings::<Test>::get(&sire), Some(50));
+
+		// Retirar a oferta não depende da taxa mínima.
+		assert_ok!(TemplateModule::offer_as_sire(RuntimeOrigin::signed(ALICE), sire, None));
+	});
+}
+
+#[test]
+fn root_can_create_kitty_on_behalf_of_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::create_kitty(RuntimeOrigin::root(), Some(BOB)));
+
+		let owned = TemplateModule::owned_ids(&BOB);
+		assert_eq!(owned.len(), 1);
+		assert_eq!(Kitties::<Test>::get(&owned[0]).unwrap().owner, BOB);
+		System::assert_last_event(Event::Created { owner: BOB, kitty_id: owned[0].clone() }.into());
+	});
+}
+
+#[test]
+fn created_event_is_indexed_by_kitty_topic() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+
+		let record = System::events().pop().unwrap();
+		assert_eq!(
+			record.event,
+			Event::Created { owner: ALICE, kitty_id: kitty_id.clone() }.into()
+		);
+		assert_eq!(record.topics, vec![TemplateModule::kitty_topic(&kitty_id)]);
+		assert_eq!(
+			System::event_topics(TemplateModule::kitty_topic(&kitty_id)),
+			vec![(System::block_number(), System::event_count() - 1)]
+		);
+	});
+}
+
+#[test]
+fn root_can_transfer_on_behalf_of_owner() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::root(),
+			BOB,
+			kitty_id.clone(),
+			Some(ALICE)
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+	});
+}
+
+#[test]
+fn only_root_can_act_on_behalf_of_others() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::create_kitty(RuntimeOrigin::signed(ALICE), Some(BOB)),
+			Error::<Test>::OnBehalfOfNotAllowed
+		);
+		assert_noop!(
+			TemplateModule::create_kitty(RuntimeOrigin::root(), None),
+			Error::<Test>::MissingOnBehalfOf
+		);
+		assert_noop!(
+			TemplateModule::create_kitty(RuntimeOrigin::none(), None),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn mint_populates_species_index() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..5 {
+			let kitty_id = create_kitty(ALICE);
+			let species = TemplateModule::species_of(&kitty_id);
+
+			assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().species, species);
+			assert!(SpeciesIndex::<Test>::contains_key(species, &kitty_id));
+		}
+	});
+}
+
+#[test]
+fn kitties_of_species_filters_and_paginates() {
+	new_test_ext().execute_with(|| {
+		// DNAs só com bytes 0 ou só com bytes 8 são da espécie 0; só com bytes 1, da espécie 1.
+		assert_ok!(TemplateModule::mint(ALICE, filled_dna(0)));
+		assert_ok!(TemplateModule::mint(BOB, filled_dna(1)));
+		assert_ok!(TemplateModule::mint(CHARLIE, filled_dna(8)));
+
+		let mut species_0 = TemplateModule::kitties_of_species(0, 0, 10);
+		species_0.sort();
+		assert_eq!(species_0, vec![filled_dna(0), filled_dna(8)]);
+		assert_eq!(TemplateModule::kitties_of_species(1, 0, 10), vec![filled_dna(1)]);
+		assert!(TemplateModule::kitties_of_species(2, 0, 10).is_empty());
+
+		// As páginas se complementam sem repetir Kitties.
+		let first = TemplateModule::kitties_of_species(0, 0, 1);
+		let second = TemplateModule::kitties_of_species(0, 1, 1);
+		assert_eq!(first.len(), 1);
+		assert_eq!(second.len(), 1);
+		assert_ne!(first, second);
+		assert!(TemplateModule::kitties_of_species(0, 2, 1).is_empty());
+	});
+}
+
+#[test]
+fn buy_kitty_charges_exactly_the_listing_price() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			900,
+			true,
+			None
+		));
+
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 100);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 100);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		System::assert_last_event(
+			Event::Sold { buyer: BOB, seller: ALICE, kitty_id, price: 100 }.into(),
+		);
+	});
+}
+
+#[test]
+fn listed_price_decays_each_block() {
+	new_test_ext().execute_with(|| {
+		let listed = create_kitty(ALICE);
+		let unlisted = create_kitty(ALICE);
+		list_kitty(ALICE, &listed, 1_000);
+
+		run_to_block(4);
+
+		assert_eq!(Kitties::<Test>::get(&listed).unwrap().price, Some(729));
+		assert_eq!(Kitties::<Test>::get(&unlisted).unwrap().price, None);
+		System::assert_last_event(
+			Event::PriceSet { owner: ALICE, kitty_id: listed, new_price: Some(729) }.into(),
+		);
+	});
+}
+
+#[test]
+fn full_decay_stops_listed_price_at_one() {
+	new_test_ext().execute_with(|| {
+		ListingDecay::set(Permill::one());
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 1_000);
+
+		run_to_block(2);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().price, Some(1));
+		System::assert_last_event(
+			Event::PriceSet { owner: ALICE, kitty_id: kitty_id.clone(), new_price: Some(1) }.into(),
+		);
+
+		// O preço mínimo não muda mais, então o Kitty continua à venda por 1.
+		System::reset_events();
+		run_to_block(4);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().price, Some(1));
+		assert!(pallet_events().is_empty());
+	});
+}
+
+#[test]
+fn decay_visits_a_bounded_number_of_kitties_per_block() {
+	new_test_ext().execute_with(|| {
+		let kitties = [create_kitty(ALICE), create_kitty(BOB), create_kitty(CHARLIE)];
+		list_kitty(ALICE, &kitties[0], 1_000);
+		list_kitty(BOB, &kitties[1], 1_000);
+		list_kitty(CHARLIE, &kitties[2], 1_000);
+		let decayed = || {
+			kitties.iter().filter(|id| Kitties::<Test>::get(id).unwrap().price == Some(900)).count()
+		};
+
+		// `MaxDecaysPerBlock` é 2 no mock.
+		run_to_block(2);
+		assert_eq!(decayed(), 2);
+
+		// O cursor continua do ponto em que parou.
+		run_to_block(3);
+		assert_eq!(decayed(), 3);
+	});
+}
+
+#[test]
+fn only_reserved_buyer_can_buy_during_reservation() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 500);
+		assert_ok!(TemplateModule::reserve_for(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			BOB,
+			100,
+			10
+		));
+		System::assert_last_event(
+			Event::Reserved { kitty_id: kitty_id.clone(), buyer: BOB, price: 100, expiry: 10 }
+				.into(),
+		);
+
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(CHARLIE),
+				kitty_id.clone(),
+				ALICE,
+				1_000,
+				true,
+				None
+			),
+			Error::<Test>::ReservedForAnother
+		);
+
+		// O comprador reservado paga o preço da reserva.
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			100,
+			true,
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 100);
+		assert!(!Reservations::<Test>::contains_key(&kitty_id));
+	});
+}
+
+#[test]
+fn anyone_can_buy_after_reservation_expires() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 500);
+		assert_ok!(TemplateModule::reserve_for(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			BOB,
+			100,
+			10
+		));
+
+		System::set_block_number(9);
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(CHARLIE),
+				kitty_id.clone(),
+				ALICE,
+				500,
+				true,
+				None
+			),
+			Error::<Test>::ReservedForAnother
+		);
+
+		System::set_block_number(10);
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_id.clone(),
+			ALICE,
+			500,
+			true,
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, CHARLIE);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE - 500);
+	});
+}
+
+#[test]
+fn cancel_reservation_reopens_the_sale() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 500);
+		assert_ok!(TemplateModule::reserve_for(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			BOB,
+			100,
+			10
+		));
+
+		assert_noop!(
+			TemplateModule::cancel_reservation(RuntimeOrigin::signed(BOB), kitty_id.clone()),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(TemplateModule::cancel_reservation(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone()
+		));
+		System::assert_last_event(
+			Event::ReservationCancelled { kitty_id: kitty_id.clone() }.into(),
+		);
+		assert_noop!(
+			TemplateModule::cancel_reservation(RuntimeOrigin::signed(ALICE), kitty_id.clone()),
+			Error::<Test>::NoReservation
+		);
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_id.clone(),
+			ALICE,
+			500,
+			true,
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, CHARLIE);
+	});
+}
+
+#[test]
+fn reservation_expiry_must_be_in_the_future() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+
+		assert_noop!(
+			TemplateModule::reserve_for(
+				RuntimeOrigin::signed(ALICE),
+				kitty_id.clone(),
+				BOB,
+				100,
+				1
+			),
+			Error::<Test>::ExpiryInPast
+		);
+		assert_noop!(
+			TemplateModule::reserve_for(RuntimeOrigin::signed(BOB), kitty_id, BOB, 100, 10),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn cooldown_remaining_counts_down_until_breedable() {
+	new_test_ext().execute_with(|| {
+		let parent_1 = create_kitty(ALICE);
+		let parent_2 = create_kitty(ALICE);
+		assert_eq!(TemplateModule::cooldown_remaining(&parent_1), None);
+
+		assert_ok!(TemplateModule::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			parent_1.clone(),
+			parent_2.clone(),
+			None
+		));
+		assert_eq!(TemplateModule::cooldown_remaining(&parent_1), Some(5));
+
+		System::set_block_number(4);
+		assert_eq!(TemplateModule::cooldown_remaining(&parent_2), Some(2));
+
+		System::set_block_number(6);
+		assert_eq!(TemplateModule::cooldown_remaining(&parent_1), None);
+		assert_eq!(TemplateModule::cooldown_remaining(&filled_dna(9)), None);
+	});
+}
+
+#[test]
+fn buying_own_kitty_fails_before_moving_funds() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(ALICE),
+				kitty_id.clone(),
+				ALICE,
+				100,
+				true,
+				None
+			),
+			Error::<Test>::TransferToSelf
+		);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().price, Some(100));
+	});
+}
+
+#[test]
+fn burn_kitty_updates_supply_counters() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		create_kitty(ALICE);
+		let species = TemplateModule::species_of(&kitty_id);
+
+		assert_ok!(TemplateModule::burn_kitty(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+
+		assert_eq!(CountForKitties::<Test>::get(), 1);
+		assert_eq!(TotalBurned::<Test>::get(), 1);
+		assert_eq!(TemplateModule::lifetime_minted(), 2);
+		assert!(!Kitties::<Test>::contains_key(&kitty_id));
+		assert!(!OwnedKitties::<Test>::contains_key(ALICE, &kitty_id));
+		assert!(!SpeciesIndex::<Test>::contains_key(species, &kitty_id));
+		System::assert_last_event(Event::Burned { owner: ALICE, kitty_id }.into());
+	});
+}
+
+#[test]
+fn only_owner_can_burn_kitty() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+
+		assert_noop!(
+			TemplateModule::burn_kitty(RuntimeOrigin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			TemplateModule::burn_kitty(RuntimeOrigin::signed(ALICE), filled_dna(9)),
+			Error::<Test>::NoKitty
+		);
+	});
+}
+
+/// Procura o primeiro nonce de `who` cujo DNA atende (ou não) à dificuldade do mock.
+fn find_nonce(who: u64, qualifying: bool) -> u64 {
+	(0..u64::MAX)
+		.find(|nonce| {
+			let dna = TemplateModule::pow_dna(&who, *nonce);
+			TemplateModule::meets_difficulty(&dna, 1) == qualifying
+		})
+		.unwrap()
+}
+
+#[test]
+fn mint_nonce_gives_distinct_dna_to_simultaneous_minters() {
+	new_test_ext().execute_with(|| {
+		// Mesmo bloco, mesma posição e mesma contagem: só a conta diferencia os DNAs.
+		assert_ne!(TemplateModule::gen_dna(&ALICE), TemplateModule::gen_dna(&BOB));
+
+		create_kitty(ALICE);
+		create_kitty(ALICE);
+		assert_eq!(MintNonce::<Test>::get(ALICE), 2);
+		assert_eq!(MintNonce::<Test>::get(BOB), 0);
+
+		// O nonce entra no DNA: com ele avançado, o mesmo estado gera outro DNA.
+		let before = TemplateModule::gen_dna(&ALICE);
+		MintNonce::<Test>::insert(ALICE, 3);
+		assert_ne!(TemplateModule::gen_dna(&ALICE), before);
+	});
+}
+
+#[test]
+fn dna_filter_rejects_blocked_dna() {
+	new_test_ext().execute_with(|| {
+		let dna = TemplateModule::gen_dna(&ALICE);
+		BlockedDna::set(vec![dna.clone()]);
+		assert_noop!(
+			TemplateModule::create_kitty(RuntimeOrigin::signed(ALICE), None),
+			Error::<Test>::DnaRejected
+		);
+
+		BlockedDna::set(vec![]);
+		assert_eq!(create_kitty(ALICE), dna);
+	});
+}
+
+#[test]
+fn create_kitty_pow_mints_with_qualifying_nonce() {
+	new_test_ext().execute_with(|| {
+		let nonce = find_nonce(ALICE, true);
+
+		assert_ok!(TemplateModule::create_kitty_pow(RuntimeOrigin::signed(ALICE), nonce));
+
+		let dna = TemplateModule::pow_dna(&ALICE, nonce);
+		assert_eq!(dna[0], 0);
+		assert_eq!(Kitties::<Test>::get(&dna).unwrap().owner, ALICE);
+		assert_noop!(
+			TemplateModule::create_kitty_pow(RuntimeOrigin::signed(ALICE), nonce),
+			Error::<Test>::DuplicateKitty
+		);
+	});
+}
+
+#[test]
+fn create_kitty_pow_rejects_non_qualifying_nonce() {
+	new_test_ext().execute_with(|| {
+		let nonce = find_nonce(ALICE, false);
+
+		assert_noop!(
+			TemplateModule::create_kitty_pow(RuntimeOrigin::signed(ALICE), nonce),
+			Error::<Test>::PowTooEasy
+		);
+	});
+}
+
+#[test]
+fn can_buy_reports_price_or_reason() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Err(BuyError::NotForSale));
+		assert_eq!(TemplateModule::can_buy(&BOB, &filled_dna(9)), Err(BuyError::NoKitty));
+
+		list_kitty(ALICE, &kitty_id, 100);
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Ok(100));
+		assert_eq!(TemplateModule::can_buy(&ALICE, &kitty_id), Err(BuyError::AlreadyOwner));
+
+		// O comprador precisa manter o depósito existencial.
+		list_kitty(ALICE, &kitty_id, INITIAL_BALANCE);
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Err(BuyError::InsufficientFunds));
+	});
+}
+
+#[test]
+fn owned_capacity_uses_default_limit() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TemplateModule::owned_capacity(&ALICE), (0, 10));
+
+		create_kitty(ALICE);
+		create_kitty(ALICE);
+		assert_eq!(TemplateModule::owned_capacity(&ALICE), (2, 10));
+	});
+}
+
+#[test]
+fn recipient_remaining_slots_counts_free_capacity() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TemplateModule::recipient_remaining_slots(&BOB), 10);
+		create_kitty(BOB);
+		create_kitty(BOB);
+		assert_eq!(TemplateModule::recipient_remaining_slots(&BOB), 8);
+
+		// O limite personalizado vale, e um limite abaixo da posse não transborda.
+		assert_ok!(TemplateModule::set_owned_limit(RuntimeOrigin::root(), BOB, Some(2)));
+		assert_eq!(TemplateModule::recipient_remaining_slots(&BOB), 0);
+		assert_ok!(TemplateModule::set_owned_limit(RuntimeOrigin::root(), BOB, Some(1)));
+		assert_eq!(TemplateModule::recipient_remaining_slots(&BOB), 0);
+
+		let kitty_id = create_kitty(ALICE);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id, None),
+			Error::<Test>::TooManyOwned
+		);
+	});
+}
+
+#[test]
+fn owned_capacity_uses_custom_limit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::set_owned_limit(RuntimeOrigin::root(), ALICE, Some(1)));
+		System::assert_last_event(Event::OwnedLimitSet { who: ALICE, limit: Some(1) }.into());
+		assert_eq!(TemplateModule::owned_capacity(&ALICE), (0, 1));
+
+		// O limite personalizado é aplicado na criação e na transferência.
+		create_kitty(ALICE);
+		assert_eq!(TemplateModule::owned_capacity(&ALICE), (1, 1));
+		assert_noop!(
+			TemplateModule::create_kitty(RuntimeOrigin::signed(ALICE), None),
+			Error::<Test>::TooManyOwned
+		);
+		let kitty_id = create_kitty(BOB);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(BOB), ALICE, kitty_id, None),
+			Error::<Test>::TooManyOwned
+		);
+
+		assert_ok!(TemplateModule::set_owned_limit(RuntimeOrigin::root(), ALICE, None));
+		assert_eq!(TemplateModule::owned_capacity(&ALICE), (1, 10));
+	});
+}
+
+#[test]
+fn custom_limit_is_root_only_and_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::set_owned_limit(RuntimeOrigin::signed(ALICE), ALICE, Some(1)),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::set_owned_limit(RuntimeOrigin::root(), ALICE, Some(11)),
+			Error::<Test>::LimitTooHigh
+		);
+	});
+}
+
+#[test]
+fn on_mint_hook_fires_once_per_mint() {
+	new_test_ext().execute_with(|| {
+		MintedKitties::take();
+
+		let first = create_kitty(ALICE);
+		let second = create_kitty(BOB);
+		assert_eq!(MintedKitties::get(), vec![(ALICE, first), (BOB, second.clone())]);
+
+		// Kitties criados por cruzamento também disparam o gancho.
+		let third = create_kitty(BOB);
+		assert_ok!(TemplateModule::breed_kitty(RuntimeOrigin::signed(BOB), second, third, None));
+		assert_eq!(MintedKitties::get().len(), 4);
+		let (owner, child) = MintedKitties::get().pop().unwrap();
+		assert_eq!(owner, BOB);
+		assert!(OwnedKitties::<Test>::contains_key(BOB, &child));
+	});
+}
+
+#[test]
+fn on_sale_hook_receives_sale_parameters() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			150,
+			true,
+			None
+		));
+
+		assert_eq!(SoldKitties::get(), vec![(BOB, ALICE, kitty_id, 100)]);
+	});
+}
+
+#[test]
+fn try_state_accepts_consistent_storage() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		create_kitty(BOB);
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_id.clone(),
+			None
+		));
+		assert_ok!(TemplateModule::burn_kitty(RuntimeOrigin::signed(BOB), kitty_id));
+
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_corrupted_storage() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+
+		// Contador fora de sincronia.
+		CountForKitties::<Test>::put(2);
+		assert!(TemplateModule::do_try_state().is_err());
+		CountForKitties::<Test>::put(1);
+		assert_ok!(TemplateModule::do_try_state());
+
+		// Kitty listado por quem não é o dono, e por dois donos.
+		OwnedKitties::<Test>::insert(BOB, &kitty_id, ());
+		assert!(TemplateModule::do_try_state().is_err());
+		OwnedKitties::<Test>::remove(BOB, &kitty_id);
+
+		// Contagem de posse fora de sincronia.
+		OwnedCount::<Test>::insert(ALICE, 2);
+		assert!(TemplateModule::do_try_state().is_err());
+		OwnedCount::<Test>::insert(ALICE, 1);
+		assert_ok!(TemplateModule::do_try_state());
+
+		// Kitty ausente das listas de posse.
+		OwnedKitties::<Test>::remove(ALICE, &kitty_id);
+		assert!(TemplateModule::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn transfer_batch_moves_every_kitty() {
+	new_test_ext().execute_with(|| {
+		let kitty_ids = vec![create_kitty(ALICE), create_kitty(ALICE), create_kitty(ALICE)];
+
+		assert_ok!(TemplateModule::transfer_batch(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			BoundedVec::truncate_from(kitty_ids.clone())
+		));
+
+		assert!(TemplateModule::owned_ids(&ALICE).is_empty());
+		for kitty_id in kitty_ids {
+			assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		}
+	});
+}
+
+#[test]
+fn transfer_batch_is_atomic() {
+	new_test_ext().execute_with(|| {
+		let mine = create_kitty(ALICE);
+		let not_mine = create_kitty(CHARLIE);
+
+		assert_noop!(
+			TemplateModule::transfer_batch(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				BoundedVec::truncate_from(vec![mine, not_mine])
+			),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn oversized_batch_fails_to_decode() {
+	// Índice do pallet no mock e índice da chamada `transfer_batch`.
+	let encode_batch = |len: usize| (2u8, 12u8, BOB, vec![filled_dna(0); len]).encode();
+
+	let call = RuntimeCall::decode(&mut &encode_batch(5)[..]).unwrap();
+	assert_eq!(
+		call,
+		RuntimeCall::TemplateModule(crate::Call::transfer_batch {
+			to: BOB,
+			kitty_ids: BoundedVec::truncate_from(vec![filled_dna(0); 5]),
+		})
+	);
+
+	assert!(RuntimeCall::decode(&mut &encode_batch(6)[..]).is_err());
+}
+
+#[test]
+fn batch_calls_charge_the_single_call_weight_per_item() {
+	let kitty_ids = BoundedVec::truncate_from(vec![filled_dna(0); 5]);
+	assert_eq!(
+		crate::Call::<Test>::transfer_batch { to: BOB, kitty_ids: kitty_ids.clone() }
+			.get_dispatch_info()
+			.weight,
+		<() as WeightInfo>::transfer().saturating_mul(5)
+	);
+	assert_eq!(
+		crate::Call::<Test>::bulk_approve { kitty_ids, spender: BOB }.get_dispatch_info().weight,
+		TemplateModule::approve_weight().saturating_mul(5)
+	);
+}
+
+#[test]
+fn batch_weight_scales_with_item_count() {
+	assert_eq!(TemplateModule::batch_weight(0), Weight::from_parts(10_000, 0));
+	assert_eq!(TemplateModule::batch_weight(5), Weight::from_parts(60_000, 0));
+}
+
+#[test]
+fn set_price_respects_price_update_cooldown() {
+	new_test_ext().execute_with(|| {
+		PriceUpdateCooldown::set(3);
+		run_to_block(2);
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+
+		run_to_block(4);
+		assert_noop!(
+			TemplateModule::set_price(
+				RuntimeOrigin::signed(ALICE),
+				kitty_id.clone(),
+				Some(95),
+				None
+			),
+			Error::<Test>::PriceUpdateTooSoon
+		);
+
+		run_to_block(5);
+		assert_ok!(TemplateModule::set_price(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			Some(95),
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().price, Some(95));
+	});
+}
+
+#[test]
+fn delisting_bypasses_price_update_cooldown() {
+	new_test_ext().execute_with(|| {
+		PriceUpdateCooldown::set(3);
+		run_to_block(2);
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+
+		assert_ok!(TemplateModule::set_price(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			None,
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().price, None);
+
+		// Anunciar de novo ainda espera a partir do último anúncio.
+		assert_noop!(
+			TemplateModule::set_price(RuntimeOrigin::signed(ALICE), kitty_id, Some(100), None),
+			Error::<Test>::PriceUpdateTooSoon
+		);
+	});
+}
+
+#[test]
+fn set_price_relative_uses_last_sale_price() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			100,
+			true,
+			None
+		));
+		assert_eq!(LastSalePrice::<Test>::get(&kitty_id), Some(100));
+
+		assert_ok!(TemplateModule::set_price_relative(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			150
+		));
+
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().price, Some(150));
+		System::assert_last_event(
+			Event::PriceSet { owner: BOB, kitty_id, new_price: Some(150) }.into(),
+		);
+	});
+}
+
+#[test]
+fn price_history_keeps_latest_sales_in_order() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		let sales = [
+			(2, ALICE, BOB, 100),
+			(3, BOB, ALICE, 110),
+			(4, ALICE, BOB, 120),
+			(5, BOB, ALICE, 130),
+		];
+		for (block, seller, buyer, price) in sales {
+			run_to_block(block);
+			list_kitty(seller, &kitty_id, price);
+			assert_ok!(TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(buyer),
+				kitty_id.clone(),
+				seller,
+				price,
+				true,
+				None
+			));
+		}
+
+		// `MaxPricePoints` é 3 no mock, então a primeira venda foi descartada.
+		assert_eq!(TemplateModule::price_history(&kitty_id), vec![(3, 110), (4, 120), (5, 130)]);
+	});
+}
+
+#[test]
+fn set_price_relative_requires_sale_history() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+
+		assert_noop!(
+			TemplateModule::set_price_relative(RuntimeOrigin::signed(ALICE), kitty_id, 110),
+			Error::<Test>::NoSaleHistory
+		);
+	});
+}
+
+#[test]
+fn split_deposit_never_loses_dust() {
+	let deposits = (0..1_000).chain([u64::MAX / 3, u64::MAX - 1, u64::MAX]);
+	for deposit in deposits {
+		let (refund, fee) = TemplateModule::split_deposit(deposit);
+		assert_eq!(refund.checked_add(fee), Some(deposit));
+		// `BurnRefundRatio` é 80% no mock.
+		assert_eq!(refund as u128, deposit as u128 * 80 / 100);
+	}
+}
+
+#[test]
+fn percent_of_rounds_down_and_saturates() {
+	assert_eq!(TemplateModule::percent_of(100, 150), 150);
+	assert_eq!(TemplateModule::percent_of(333, 110), 366);
+	assert_eq!(TemplateModule::percent_of(u64::MAX, 100), u64::MAX);
+	assert_eq!(TemplateModule::percent_of(u64::MAX, 200), u64::MAX);
+}
+
+#[test]
+fn kitty_of_owner_by_index_follows_ownership_map() {
+	new_test_ext().execute_with(|| {
+		let first = create_kitty(ALICE);
+		create_kitty(ALICE);
+		create_kitty(ALICE);
+		let owned = TemplateModule::owned_ids(&ALICE);
+		for (index, kitty_id) in owned.iter().enumerate() {
+			let found = TemplateModule::kitty_of_owner_by_index(&ALICE, index as u32);
+			assert_eq!(found, Some(kitty_id.clone()));
+		}
+		assert_eq!(TemplateModule::kitty_of_owner_by_index(&ALICE, 3), None);
+
+		// Depois da transferência, os índices seguem a posse restante.
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			first.clone(),
+			None
+		));
+		let owned = TemplateModule::owned_ids(&ALICE);
+		assert_eq!(owned.len(), 2);
+		assert!(!owned.contains(&first));
+		assert_eq!(TemplateModule::kitty_of_owner_by_index(&ALICE, 0), Some(owned[0].clone()));
+		assert_eq!(TemplateModule::kitty_of_owner_by_index(&ALICE, 1), Some(owned[1].clone()));
+		assert_eq!(TemplateModule::kitty_of_owner_by_index(&ALICE, 2), None);
+		assert_eq!(TemplateModule::kitty_of_owner_by_index(&BOB, 0), Some(first));
+		assert_eq!(TemplateModule::kitty_of_owner_by_index(&CHARLIE, 0), None);
+	});
+}
+
+/// Eventos emitidos por este pallet desde o início do bloco.
+fn pallet_events() -> Vec<Event<Test>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::TemplateModule(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn transferred_event_reports_owned_counts_after_the_move() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		create_kitty(ALICE);
+		create_kitty(ALICE);
+		create_kitty(BOB);
+
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_id.clone(),
+			None
+		));
+
+		System::assert_last_event(
+			Event::Transferred {
+				from: ALICE,
+				to: BOB,
+				kitty_id,
+				reason: TransferReason::Direct,
+				from_remaining: 2,
+				to_total: 2,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_batch_emits_per_item_events_by_default() {
+	new_test_ext().execute_with(|| {
+		let kitty_ids = vec![create_kitty(ALICE), create_kitty(ALICE)];
+		System::reset_events();
+
+		assert_ok!(TemplateModule::transfer_batch(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			BoundedVec::truncate_from(kitty_ids.clone())
+		));
+
+		assert_eq!(
+			pallet_events(),
+			vec![
+				Event::Transferred {
+					from: ALICE,
+					to: BOB,
+					kitty_id: kitty_ids[0].clone(),
+					reason: TransferReason::Direct,
+					from_remaining: 1,
+					to_total: 1,
+				},
+				Event::Transferred {
+					from: ALICE,
+					to: BOB,
+					kitty_id: kitty_ids[1].clone(),
+					reason: TransferReason::Direct,
+					from_remaining: 0,
+					to_total: 2,
+				},
+				Event::BatchTransferred { from: ALICE, to: BOB, count: 2 },
+			]
+		);
+	});
+}
+
+#[test]
+fn transfer_batch_emits_only_summary_when_per_item_events_are_off() {
+	new_test_ext().execute_with(|| {
+		EmitPerItemEvents::set(false);
+		let kitty_ids = vec![create_kitty(ALICE), create_kitty(ALICE)];
+		System::reset_events();
+
+		assert_ok!(TemplateModule::transfer_batch(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			BoundedVec::truncate_from(kitty_ids.clone())
+		));
+
+		assert_eq!(
+			pallet_events(),
+			vec![Event::BatchTransferred { from: ALICE, to: BOB, count: 2 }]
+		);
+		let mut owned = TemplateModule::owned_ids(&BOB);
+		owned.sort();
+		let mut expected = kitty_ids;
+		expected.sort();
+		assert_eq!(owned, expected);
+	});
+}
+
+#[test]
+fn transfer_weight_comes_from_weight_info() {
+	assert_eq!(
+		crate::Call::<Test>::transfer { to: BOB, kitty_id: filled_dna(0), on_behalf_of: None }
+			.get_dispatch_info()
+			.weight,
+		<() as WeightInfo>::transfer()
+	);
+}
+
+#[test]
+fn set_price_rejects_zero() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_noop!(
+			TemplateModule::set_price(
+				RuntimeOrigin::signed(ALICE),
+				kitty_id.clone(),
+				Some(0),
+				None
+			),
+			Error::<Test>::ZeroPrice
+		);
+		assert_noop!(
+			TemplateModule::buy_kitty(RuntimeOrigin::signed(BOB), kitty_id, ALICE, 0, true, None),
+			Error::<Test>::NotForSale
+		);
+	});
+}
+
+/// Converte `bytes` num nome limitado por `MaxNameLength`.
+fn name(bytes: &[u8]) -> BoundedVec<u8, <Test as crate::Config>::MaxNameLength> {
+	BoundedVec::truncate_from(bytes.to_vec())
+}
+
+#[test]
+fn set_name_accepts_printable_ascii() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::set_name(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			name(b"Tom ~1")
+		));
+		assert_eq!(KittyNames::<Test>::get(&kitty_id), Some(name(b"Tom ~1")));
+		System::assert_last_event(
+			Event::NameSet { owner: ALICE, kitty_id: kitty_id.clone(), name: name(b"Tom ~1") }
+				.into(),
+		);
+
+		assert_noop!(
+			TemplateModule::set_name(RuntimeOrigin::signed(BOB), kitty_id, name(b"Jerry")),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn set_name_rejects_disallowed_bytes() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		for bad in [&b"Tom\n"[..], b"\0", b"Gat\xc3\xa3o", b"\x7f"] {
+			assert_noop!(
+				TemplateModule::set_name(RuntimeOrigin::signed(ALICE), kitty_id, name(bad)),
+				Error::<Test>::InvalidName
+			);
+		}
+	});
+}
+
+#[test]
+fn transfer_with_memo_emits_memo() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		let memo: BoundedVec<u8, <Test as crate::Config>::MaxMemo> =
+			BoundedVec::truncate_from(b"aluguel".to_vec());
+		System::reset_events();
+
+		assert_ok!(TemplateModule::transfer_with_memo(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_id.clone(),
+			memo.clone()
+		));
+
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		assert_eq!(
+			pallet_events(),
+			vec![
+				Event::Transferred {
+					from: ALICE,
+					to: BOB,
+					kitty_id: kitty_id.clone(),
+					reason: TransferReason::Gift,
+					from_remaining: 0,
+					to_total: 1,
+				},
+				Event::TransferredWithMemo { from: ALICE, to: BOB, kitty_id, memo },
+			]
+		);
+	});
+}
+
+#[test]
+fn transfer_with_memo_is_bounded_by_max_memo() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		let full = vec![b'x'; 16];
+		let too_long = vec![b'x'; 17];
+
+		// Um memo acima de `MaxMemo` nem chega a ser decodificado.
+		let call = crate::Call::<Test>::transfer_with_memo {
+			to: BOB,
+			kitty_id: kitty_id.clone(),
+			memo: BoundedVec::truncate_from(full.clone()),
+		};
+		let mut encoded = call.encode();
+		let memo_at = encoded.len() - full.encode().len();
+		encoded.truncate(memo_at);
+		encoded.extend(too_long.encode());
+		assert!(crate::Call::<Test>::decode(&mut &encoded[..]).is_err());
+
+		assert_ok!(TemplateModule::transfer_with_memo(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_id,
+			BoundedVec::truncate_from(full)
+		));
+	});
+}
+
+#[test]
+fn floor_listing_returns_cheapest_kitty_for_sale() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TemplateModule::floor_listing(), None);
+
+		let expensive = create_kitty(ALICE);
+		let cheapest = create_kitty(ALICE);
+		let middle = create_kitty(BOB);
+		let _unlisted = create_kitty(BOB);
+		list_kitty(ALICE, &expensive, 300);
+		list_kitty(ALICE, &cheapest, 50);
+		list_kitty(BOB, &middle, 120);
+
+		assert_eq!(TemplateModule::floor_listing(), Some((cheapest.clone(), 50)));
+
+		assert_ok!(TemplateModule::set_price(RuntimeOrigin::signed(ALICE), cheapest, None, None));
+		assert_eq!(TemplateModule::floor_listing(), Some((middle, 120)));
+	});
+}
+
+#[test]
+fn floor_listing_breaks_ties_by_smallest_dna() {
+	new_test_ext().execute_with(|| {
+		let kitty_1 = create_kitty(ALICE);
+		let kitty_2 = create_kitty(BOB);
+		list_kitty(ALICE, &kitty_1, 50);
+		list_kitty(BOB, &kitty_2, 50);
+
+		let winner = kitty_1.min(kitty_2);
+		assert_eq!(TemplateModule::floor_listing(), Some((winner.clone(), 50)));
+		assert_eq!(TemplateModule::floor_listing(), Some((winner, 50)));
+	});
+}
+
+#[test]
+fn cancel_burn_before_delay_preserves_kitty() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::request_burn(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+		System::assert_last_event(
+			Event::BurnRequested { owner: ALICE, kitty_id: kitty_id.clone(), executable_at: 4 }
+				.into(),
+		);
+
+		run_to_block(3);
+		assert_noop!(
+			TemplateModule::finalize_burn(RuntimeOrigin::signed(BOB), kitty_id.clone()),
+			Error::<Test>::BurnDelayNotElapsed
+		);
+		assert_ok!(TemplateModule::cancel_burn(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+		System::assert_last_event(Event::BurnCancelled { kitty_id: kitty_id.clone() }.into());
+
+		run_to_block(10);
+		assert_noop!(
+			TemplateModule::finalize_burn(RuntimeOrigin::signed(BOB), kitty_id.clone()),
+			Error::<Test>::NoPendingBurn
+		);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, ALICE);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id, None));
+	});
+}
+
+#[test]
+fn finalize_burn_after_delay_burns_kitty() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::request_burn(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+
+		run_to_block(4);
+		assert_ok!(TemplateModule::finalize_burn(RuntimeOrigin::signed(BOB), kitty_id.clone()));
+		assert!(!Kitties::<Test>::contains_key(&kitty_id));
+		assert!(TemplateModule::owned_ids(&ALICE).is_empty());
+		assert_eq!(PendingBurns::<Test>::get(&kitty_id), None);
+		System::assert_last_event(Event::Burned { owner: ALICE, kitty_id }.into());
+	});
+}
+
+#[test]
+fn pending_burn_blocks_transfer_and_sale() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+		assert_ok!(TemplateModule::request_burn(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id.clone(), None),
+			Error::<Test>::BurnPending
+		);
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(BOB),
+				kitty_id.clone(),
+				ALICE,
+				100,
+				true,
+				None
+			),
+			Error::<Test>::BurnPending
+		);
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Err(BuyError::BurnPending));
+		assert_noop!(
+			TemplateModule::request_burn(RuntimeOrigin::signed(ALICE), kitty_id),
+			Error::<Test>::BurnPending
+		);
+	});
+}
+
+#[test]
+fn no_kitty_is_double_owned_after_transfers() {
+	new_test_ext().execute_with(|| {
+		let kitty_1 = create_kitty(ALICE);
+		let kitty_2 = create_kitty(ALICE);
+		let kitty_3 = create_kitty(BOB);
+
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_1.clone(),
+			None
+		));
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(BOB),
+			CHARLIE,
+			kitty_1.clone(),
+			None
+		));
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			kitty_3.clone(),
+			None
+		));
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			kitty_2.clone(),
+			None
+		));
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			kitty_1.clone(),
+			None
+		));
+
+		for kitty_id in [kitty_1, kitty_2, kitty_3] {
+			let owner = Kitties::<Test>::get(&kitty_id).unwrap().owner;
+			assert_eq!(TemplateModule::accounts_holding(&kitty_id), vec![owner]);
+		}
+		assert!(TemplateModule::accounts_holding(&filled_dna(0xff)).is_empty());
+	});
+}
+
+#[test]
+fn private_sale_only_sells_to_designated_buyer() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 50);
+		assert_ok!(TemplateModule::private_sale(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			BOB,
+			80
+		));
+		System::assert_last_event(
+			Event::PrivateSaleCreated { kitty_id: kitty_id.clone(), buyer: BOB, price: 80 }.into(),
+		);
+
+		// O preço público não vale enquanto houver venda privada.
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(CHARLIE),
+				kitty_id.clone(),
+				ALICE,
+				100,
+				true,
+				None
+			),
+			Error::<Test>::NotForSale
+		);
+		assert_eq!(TemplateModule::can_buy(&CHARLIE, &kitty_id), Err(BuyError::NotForSale));
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Ok(80));
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			80,
+			true,
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 80);
+		assert!(!PrivateSales::<Test>::contains_key(&kitty_id));
+	});
+}
+
+#[test]
+fn cancel_private_sale_restores_public_listing() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 50);
+		assert_ok!(TemplateModule::private_sale(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			BOB,
+			80
+		));
+
+		assert_noop!(
+			TemplateModule::cancel_private_sale(RuntimeOrigin::signed(BOB), kitty_id.clone()),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(TemplateModule::cancel_private_sale(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone()
+		));
+		System::assert_last_event(
+			Event::PrivateSaleCancelled { kitty_id: kitty_id.clone() }.into(),
+		);
+		assert_noop!(
+			TemplateModule::cancel_private_sale(RuntimeOrigin::signed(ALICE), kitty_id.clone()),
+			Error::<Test>::NoPrivateSale
+		);
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_id.clone(),
+			ALICE,
+			50,
+			true,
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, CHARLIE);
+	});
+}
+
+#[test]
+fn feed_levels_up_after_each_cooldown() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().level, 0);
+
+		assert_ok!(TemplateModule::feed(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+		System::assert_last_event(Event::Fed { kitty_id: kitty_id.clone(), new_level: 1 }.into());
+
+		run_to_block(3);
+		assert_ok!(TemplateModule::feed(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+		let kitty = Kitties::<Test>::get(&kitty_id).unwrap();
+		assert_eq!((kitty.level, kitty.last_fed), (2, 3));
+
+		assert_noop!(
+			TemplateModule::feed(RuntimeOrigin::signed(BOB), kitty_id),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn feed_enforces_cooldown() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::feed(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+
+		assert_noop!(
+			TemplateModule::feed(RuntimeOrigin::signed(ALICE), kitty_id.clone()),
+			Error::<Test>::FeedTooSoon
+		);
+		run_to_block(2);
+		assert_noop!(
+			TemplateModule::feed(RuntimeOrigin::signed(ALICE), kitty_id.clone()),
+			Error::<Test>::FeedTooSoon
+		);
+		run_to_block(3);
+		assert_ok!(TemplateModule::feed(RuntimeOrigin::signed(ALICE), kitty_id));
+	});
+}
+
+#[test]
+fn feed_caps_level_at_max() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		Kitties::<Test>::mutate(&kitty_id, |kitty| kitty.as_mut().unwrap().level = MAX_LEVEL);
+
+		assert_ok!(TemplateModule::feed(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+		System::assert_last_event(Event::Fed { kitty_id, new_level: MAX_LEVEL }.into());
+	});
+}
+
+/// Motivos dos eventos `Transferred` emitidos desde o início do bloco.
+fn transfer_reasons() -> Vec<TransferReason> {
+	pallet_events()
+		.into_iter()
+		.filter_map(|event| match event {
+			Event::Transferred { reason, .. } => Some(reason),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn transfer_reports_direct_or_force_reason() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		System::reset_events();
+
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_id.clone(),
+			None
+		));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::root(), CHARLIE, kitty_id, Some(BOB)));
+
+		assert_eq!(transfer_reasons(), vec![TransferReason::Direct, TransferReason::Force]);
+	});
+}
+
+#[test]
+fn buy_kitty_reports_sale_reason_once() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+		System::reset_events();
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			100,
+			true,
+			None
+		));
+
+		assert_eq!(
+			pallet_events(),
+			vec![
+				Event::Transferred {
+					from: ALICE,
+					to: BOB,
+					kitty_id: kitty_id.clone(),
+					reason: TransferReason::Sale,
+					from_remaining: 0,
+					to_total: 1,
+				},
+				Event::Sold { buyer: BOB, seller: ALICE, kitty_id, price: 100 },
+			]
+		);
+	});
+}
+
+#[test]
+fn transfer_with_memo_reports_gift_reason() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		System::reset_events();
+
+		assert_ok!(TemplateModule::transfer_with_memo(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_id,
+			BoundedVec::truncate_from(b"parabens".to_vec())
+		));
+
+		assert_eq!(transfer_reasons(), vec![TransferReason::Gift]);
+	});
+}
+
+#[test]
+fn set_price_enforces_max_listings_per_account() {
+	new_test_ext().execute_with(|| {
+		MaxListingsPerAccount::set(2);
+		let kitty_1 = create_kitty(ALICE);
+		let kitty_2 = create_kitty(ALICE);
+		let kitty_3 = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_1, 10);
+		list_kitty(ALICE, &kitty_2, 20);
+		assert_eq!(ListingCount::<Test>::get(ALICE), Some(2));
+
+		assert_noop!(
+			TemplateModule::set_price(RuntimeOrigin::signed(ALICE), kitty_3, Some(30), None),
+			Error::<Test>::TooManyListings
+		);
+		// Trocar o preço de um Kitty já anunciado não ocupa outra vaga.
+		list_kitty(ALICE, &kitty_2, 25);
+		assert_eq!(ListingCount::<Test>::get(ALICE), Some(2));
+	});
+}
+
+#[test]
+fn delisting_and_selling_free_listing_slots() {
+	new_test_ext().execute_with(|| {
+		MaxListingsPerAccount::set(2);
+		let kitty_1 = create_kitty(ALICE);
+		let kitty_2 = create_kitty(ALICE);
+		let kitty_3 = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_1, 10);
+		list_kitty(ALICE, &kitty_2, 20);
+
+		assert_ok!(TemplateModule::set_price(
+			RuntimeOrigin::signed(ALICE),
+			kitty_1.clone(),
+			None,
+			None
+		));
+		assert_eq!(ListingCount::<Test>::get(ALICE), Some(1));
+		list_kitty(ALICE, &kitty_3, 30);
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_2,
+			ALICE,
+			20,
+			true,
+			None
+		));
+		assert_ok!(TemplateModule::burn_kitty(RuntimeOrigin::signed(ALICE), kitty_3));
+		assert_eq!(ListingCount::<Test>::get(ALICE), None);
+		list_kitty(ALICE, &kitty_1, 10);
+		assert_eq!(ListingCount::<Test>::get(ALICE), Some(1));
+	});
+}
+
+#[test]
+fn approved_spender_can_transfer_once() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(BOB), kitty_id.clone(), BOB),
+			Error::<Test>::NotApproved
+		);
+
+		assert_ok!(TemplateModule::approve(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			Some(BOB)
+		));
+		System::assert_last_event(
+			Event::Approved { owner: ALICE, kitty_id: kitty_id.clone(), spender: Some(BOB) }.into(),
+		);
+		assert_ok!(TemplateModule::transfer_from(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			CHARLIE
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, CHARLIE);
+
+		// A autorização não sobrevive à mudança de dono.
+		assert!(!Approvals::<Test>::contains_key(&kitty_id));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(BOB), kitty_id, BOB),
+			Error::<Test>::NotApproved
+		);
+	});
+}
+
+#[test]
+fn bulk_approve_lets_spender_transfer_all() {
+	new_test_ext().execute_with(|| {
+		let kitty_ids = vec![create_kitty(ALICE), create_kitty(ALICE), create_kitty(ALICE)];
+		System::reset_events();
+
+		assert_ok!(TemplateModule::bulk_approve(
+			RuntimeOrigin::signed(ALICE),
+			BoundedVec::truncate_from(kitty_ids.clone()),
+			BOB
+		));
+		let approved: Vec<_> = kitty_ids
+			.iter()
+			.map(|kitty_id| Event::Approved {
+				owner: ALICE,
+				kitty_id: kitty_id.clone(),
+				spender: Some(BOB),
+			})
+			.collect();
+		assert_eq!(pallet_events(), approved);
+
+		for kitty_id in &kitty_ids {
+			assert_ok!(TemplateModule::transfer_from(
+				RuntimeOrigin::signed(BOB),
+				kitty_id.clone(),
+				BOB
+			));
+		}
+		assert_eq!(OwnedCount::<Test>::get(BOB), 3);
+	});
+}
+
+#[test]
+fn bulk_approve_fails_atomically_on_foreign_kitty() {
+	new_test_ext().execute_with(|| {
+		let mine = create_kitty(ALICE);
+		let foreign = create_kitty(CHARLIE);
+
+		assert_noop!(
+			TemplateModule::bulk_approve(
+				RuntimeOrigin::signed(ALICE),
+				BoundedVec::truncate_from(vec![mine.clone(), foreign]),
+				BOB
+			),
+			Error::<Test>::NotOwner
+		);
+		assert!(!Approvals::<Test>::contains_key(&mine));
+	});
+}
+
+#[test]
+fn listed_value_sums_listed_kitties_only() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TemplateModule::listed_value(&ALICE), 0);
+
+		let kitty_1 = create_kitty(ALICE);
+		let kitty_2 = create_kitty(ALICE);
+		let _unlisted = create_kitty(ALICE);
+		let other = create_kitty(BOB);
+		list_kitty(ALICE, &kitty_1, 120);
+		list_kitty(ALICE, &kitty_2, 30);
+		list_kitty(BOB, &other, 500);
+
+		assert_eq!(TemplateModule::listed_value(&ALICE), 150);
+		assert_eq!(TemplateModule::listed_value(&BOB), 500);
+	});
+}
+
+#[test]
+fn listed_value_saturates() {
+	new_test_ext().execute_with(|| {
+		let kitty_1 = create_kitty(ALICE);
+		let kitty_2 = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_1, u64::MAX);
+		list_kitty(ALICE, &kitty_2, 1);
+
+		assert_eq!(TemplateModule::listed_value(&ALICE), u64::MAX);
+	});
+}
+
+#[test]
+fn listings_of_pages_through_listed_kitties_only() {
+	new_test_ext().execute_with(|| {
+		assert!(TemplateModule::listings_of(&ALICE, 0, 10).is_empty());
+
+		let kitty_1 = create_kitty(ALICE);
+		let kitty_2 = create_kitty(ALICE);
+		let _unlisted = create_kitty(ALICE);
+		let other = create_kitty(BOB);
+		list_kitty(ALICE, &kitty_1, 120);
+		list_kitty(ALICE, &kitty_2, 30);
+		list_kitty(BOB, &other, 500);
+
+		let mut all = TemplateModule::listings_of(&ALICE, 0, 10);
+		all.sort();
+		let mut expected = vec![(kitty_1, 120), (kitty_2, 30)];
+		expected.sort();
+		assert_eq!(all, expected);
+
+		let first_page = TemplateModule::listings_of(&ALICE, 0, 1);
+		let second_page = TemplateModule::listings_of(&ALICE, 1, 1);
+		assert_eq!(first_page.len(), 1);
+		assert_eq!(second_page.len(), 1);
+		assert_ne!(first_page, second_page);
+		assert!(TemplateModule::listings_of(&ALICE, 2, 1).is_empty());
+		assert_eq!(TemplateModule::listings_of(&BOB, 0, 10), vec![(other, 500)]);
+	});
+}
+
+#[test]
+fn transfer_lock_blocks_transfers_until_it_passes() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+		assert_noop!(
+			TemplateModule::lock_transfer(RuntimeOrigin::signed(ALICE), kitty_id.clone(), None),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::lock_transfer(RuntimeOrigin::root(), kitty_id.clone(), Some(5)));
+		System::assert_last_event(
+			Event::TransferLockSet { kitty_id: kitty_id.clone(), until: Some(5) }.into(),
+		);
+
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id.clone(), None),
+			Error::<Test>::TransferLocked
+		);
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(BOB),
+				kitty_id.clone(),
+				ALICE,
+				100,
+				true,
+				None
+			),
+			Error::<Test>::TransferLocked
+		);
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Err(BuyError::TransferLocked));
+
+		run_to_block(5);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id, None));
+	});
+}
+
+#[test]
+fn admin_can_shorten_or_extend_transfer_lock() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::lock_transfer(RuntimeOrigin::root(), kitty_id.clone(), Some(5)));
+
+		// Estende o bloqueio além do prazo original.
+		assert_ok!(TemplateModule::lock_transfer(RuntimeOrigin::root(), kitty_id.clone(), Some(8)));
+		run_to_block(6);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id.clone(), None),
+			Error::<Test>::TransferLocked
+		);
+
+		// Encurta o bloqueio para o bloco atual, liberando a transferência.
+		assert_ok!(TemplateModule::lock_transfer(RuntimeOrigin::root(), kitty_id.clone(), Some(6)));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id, None));
+	});
+}
+
+#[test]
+fn snapshot_pages_cover_every_kitty_once() {
+	new_test_ext().execute_with(|| {
+		let mut expected: Vec<_> = (0..5).map(|_| create_kitty(ALICE)).collect();
+		expected.push(create_kitty(BOB));
+		list_kitty(BOB, &expected[5], 70);
+
+		let mut seen = Vec::new();
+		let mut cursor = None;
+		loop {
+			let (page, next) = TemplateModule::snapshot(cursor, 2);
+			assert!(page.len() <= 2);
+			for (kitty_id, details) in page {
+				let kitty = Kitties::<Test>::get(&kitty_id).unwrap();
+				assert_eq!(details, KittyDetailsOf::<Test>::from(kitty));
+				seen.push(kitty_id);
+			}
+			match next {
+				Some(_) => cursor = next,
+				None => break,
+			}
+		}
+
+		seen.sort();
+		expected.sort();
+		assert_eq!(seen, expected);
+	});
+}
+
+#[test]
+fn snapshot_returns_no_cursor_on_last_page() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TemplateModule::snapshot(None, 10), (vec![], None));
+
+		create_kitty(ALICE);
+		create_kitty(ALICE);
+		let (page, next) = TemplateModule::snapshot(None, 2);
+		assert_eq!((page.len(), next), (2, None));
+	});
+}
+
+#[test]
+fn offer_escrows_funds_until_accepted() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			200,
+			10
+		));
+		System::assert_last_event(
+			Event::OfferMade { kitty_id: kitty_id.clone(), bidder: BOB, amount: 200, expiry: 10 }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 200);
+		assert_eq!(Balances::free_balance(TemplateModule::account_id()), 200);
+
+		assert_ok!(TemplateModule::accept_offer(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			BOB
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 200);
+		assert_eq!(LastSalePrice::<Test>::get(&kitty_id), Some(200));
+		assert!(!Offers::<Test>::contains_key(&kitty_id, BOB));
+		System::assert_last_event(
+			Event::OfferAccepted { kitty_id, bidder: BOB, amount: 200 }.into(),
+		);
+	});
+}
+
+#[test]
+fn reject_offer_refunds_and_clears_offer() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			200,
+			10
+		));
+
+		assert_noop!(
+			TemplateModule::reject_offer(RuntimeOrigin::signed(CHARLIE), kitty_id.clone(), BOB),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(TemplateModule::reject_offer(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			BOB
+		));
+		System::assert_last_event(
+			Event::OfferRejected { kitty_id: kitty_id.clone(), bidder: BOB }.into(),
+		);
+
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		assert!(!Offers::<Test>::contains_key(&kitty_id, BOB));
+		assert_noop!(
+			TemplateModule::accept_offer(RuntimeOrigin::signed(ALICE), kitty_id, BOB),
+			Error::<Test>::NoOffer
+		);
+	});
+}
+
+#[test]
+fn only_current_owner_can_reject_offer() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			200,
+			10
+		));
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			kitty_id.clone(),
+			None
+		));
+
+		assert_noop!(
+			TemplateModule::reject_offer(RuntimeOrigin::signed(ALICE), kitty_id.clone(), BOB),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(TemplateModule::reject_offer(RuntimeOrigin::signed(CHARLIE), kitty_id, BOB));
+	});
+}
+
+#[test]
+fn expired_offer_can_be_withdrawn_but_not_accepted() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			200,
+			3
+		));
+		assert_noop!(
+			TemplateModule::make_offer(RuntimeOrigin::signed(BOB), kitty_id.clone(), 300, 5),
+			Error::<Test>::DuplicateOffer
+		);
+
+		run_to_block(3);
+		assert_noop!(
+			TemplateModule::accept_offer(RuntimeOrigin::signed(ALICE), kitty_id.clone(), BOB),
+			Error::<Test>::OfferExpired
+		);
+		assert_ok!(TemplateModule::withdraw_offer(RuntimeOrigin::signed(BOB), kitty_id));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn on_idle_refunds_expired_offers_within_weight_budget() {
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		let kitties = [create_kitty(ALICE), create_kitty(ALICE)];
+		for (bidder, kitty_id, expiry) in [(BOB, 0, 3), (CHARLIE, 0, 3), (BOB, 1, 10)] {
+			let origin = RuntimeOrigin::signed(bidder);
+			assert_ok!(TemplateModule::make_offer(origin, kitties[kitty_id].clone(), 100, expiry));
+		}
+		run_to_block(3);
+
+		let base = <Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+		let per_offer = <() as WeightInfo>::cleanup_expired_offer();
+		let two_offers = base.saturating_add(per_offer.saturating_mul(2));
+
+		// Sem orçamento para uma oferta inteira, nada é visitado.
+		let too_little = base.saturating_add(per_offer).saturating_sub(Weight::from_parts(1, 0));
+		assert_eq!(TemplateModule::on_idle(3, too_little), Weight::zero());
+		assert_eq!(Offers::<Test>::iter().count(), 3);
+
+		// O peso usado nunca passa do orçamento, e a varredura continua no bloco seguinte.
+		assert_eq!(TemplateModule::on_idle(3, two_offers), two_offers);
+		assert!(Offers::<Test>::iter().count() >= 2);
+		TemplateModule::on_idle(4, Weight::MAX);
+
+		assert_eq!(
+			Offers::<Test>::iter_keys().collect::<Vec<_>>(),
+			vec![(kitties[1].clone(), BOB)]
+		);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE);
+		assert_eq!(TemplateModule::total_reserved_offers(&BOB), 100);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn total_reserved_offers_tracks_every_offer_change() {
+	new_test_ext().execute_with(|| {
+		let kitties = [create_kitty(ALICE), create_kitty(ALICE), create_kitty(CHARLIE)];
+		for (kitty_id, amount) in kitties.iter().zip([100, 50, 30]) {
+			assert_ok!(TemplateModule::make_offer(
+				RuntimeOrigin::signed(BOB),
+				kitty_id.clone(),
+				amount,
+				10
+			));
+		}
+		assert_eq!(TemplateModule::total_reserved_offers(&BOB), 180);
+
+		assert_ok!(TemplateModule::withdraw_offer(RuntimeOrigin::signed(BOB), kitties[0].clone()));
+		assert_eq!(TemplateModule::total_reserved_offers(&BOB), 80);
+
+		assert_ok!(TemplateModule::reject_offer(
+			RuntimeOrigin::signed(ALICE),
+			kitties[1].clone(),
+			BOB
+		));
+		assert_eq!(TemplateModule::total_reserved_offers(&BOB), 30);
+		assert_ok!(TemplateModule::do_try_state());
+
+		assert_ok!(TemplateModule::accept_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			kitties[2].clone(),
+			BOB
+		));
+		assert_eq!(TemplateModule::total_reserved_offers(&BOB), 0);
+		assert!(!OfferedTotal::<Test>::contains_key(BOB));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn clear_offers_refunds_every_bidder() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			200,
+			10
+		));
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_id.clone(),
+			300,
+			10
+		));
+
+		assert_noop!(
+			TemplateModule::clear_offers(RuntimeOrigin::signed(BOB), kitty_id.clone()),
+			Error::<Test>::NotOwner
+		);
+		System::reset_events();
+		assert_ok!(TemplateModule::clear_offers(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+
+		let mut rejected = pallet_events();
+		rejected.sort_by_key(|event| match event {
+			Event::OfferRejected { bidder, .. } => *bidder,
+			_ => u64::MAX,
+		});
+		assert_eq!(
+			rejected,
+			vec![
+				Event::OfferRejected { kitty_id: kitty_id.clone(), bidder: BOB },
+				Event::OfferRejected { kitty_id: kitty_id.clone(), bidder: CHARLIE },
+			]
+		);
+		assert_eq!(Offers::<Test>::iter_prefix(&kitty_id).count(), 0);
+		assert!(!OfferCount::<Test>::contains_key(&kitty_id));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn make_offer_respects_max_offers_per_kitty() {
+	new_test_ext().execute_with(|| {
+		MaxOffersPerKitty::set(1);
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			200,
+			10
+		));
+		assert_noop!(
+			TemplateModule::make_offer(RuntimeOrigin::signed(CHARLIE), kitty_id.clone(), 300, 10),
+			Error::<Test>::TooManyOffers
+		);
+
+		// Retirar uma oferta libera a vaga.
+		assert_ok!(TemplateModule::withdraw_offer(RuntimeOrigin::signed(BOB), kitty_id.clone()));
+		assert!(!OfferCount::<Test>::contains_key(&kitty_id));
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_id.clone(),
+			300,
+			10
+		));
+		assert_eq!(OfferCount::<Test>::get(&kitty_id), 1);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn sweep_buys_cheapest_listings_within_both_limits() {
+	new_test_ext().execute_with(|| {
+		let cheap = create_kitty(ALICE);
+		let middle = create_kitty(ALICE);
+		let dear = create_kitty(ALICE);
+		let own = create_kitty(BOB);
+		for (who, kitty_id, price) in
+			[(ALICE, &middle, 50), (ALICE, &cheap, 30), (ALICE, &dear, 80)]
+		{
+			list_kitty(who, kitty_id, price);
+		}
+		list_kitty(BOB, &own, 10);
+		System::reset_events();
+
+		// O anúncio do próprio BOB é pulado, e o de 80 passaria do gasto máximo.
+		assert_ok!(TemplateModule::sweep(RuntimeOrigin::signed(BOB), 100, 5));
+		let sales: Vec<_> = pallet_events()
+			.into_iter()
+			.filter_map(|event| match event {
+				Event::Sold { kitty_id, price, .. } => Some((kitty_id, price)),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(sales, vec![(cheap, 30), (middle, 50)]);
+		System::assert_last_event(Event::Swept { buyer: BOB, count: 2, total_spent: 80 }.into());
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 80);
+
+		// Com `max_count` 1, só o mais barato é comprado, mesmo com gasto de sobra.
+		assert_ok!(TemplateModule::sweep(RuntimeOrigin::signed(CHARLIE), 1_000, 1));
+		assert_eq!(Kitties::<Test>::get(&own).unwrap().owner, CHARLIE);
+		assert_eq!(Kitties::<Test>::get(&dear).unwrap().owner, ALICE);
+		System::assert_last_event(
+			Event::Swept { buyer: CHARLIE, count: 1, total_spent: 10 }.into(),
+		);
+	});
+}
+
+#[test]
+fn balance_wrapper_moves_funds_and_reports_spendable_balance() {
+	new_test_ext().execute_with(|| {
+		let existential = <Test as pallet_balances::Config>::ExistentialDeposit::get();
+		let spendable = |who, preservation| TemplateModule::spendable_balance(&who, preservation);
+		assert_eq!(spendable(ALICE, Preservation::Expendable), INITIAL_BALANCE);
+		assert_eq!(spendable(ALICE, Preservation::Preserve), INITIAL_BALANCE - existential);
+
+		// Preservar a conta de origem impede esvaziá-la.
+		assert!(
+			TemplateModule::transfer_funds(&ALICE, &BOB, 1_000, Preservation::Preserve).is_err()
+		);
+		assert_ok!(TemplateModule::transfer_funds(&ALICE, &BOB, 400, Preservation::Preserve));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + 400);
+
+		// Os fundos guardados numa oferta saem do saldo disponível pelo mesmo caminho.
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::make_offer(RuntimeOrigin::signed(BOB), kitty_id, 300, 10));
+		assert_eq!(spendable(BOB, Preservation::Expendable), INITIAL_BALANCE + 100);
+		assert_eq!(Balances::free_balance(TemplateModule::account_id()), 300);
+	});
+}
+
+#[test]
+fn sweep_stops_at_first_purchase_the_buyer_cannot_afford() {
+	new_test_ext().execute_with(|| {
+		let cheap = create_kitty(ALICE);
+		let dear = create_kitty(ALICE);
+		list_kitty(ALICE, &cheap, 600);
+		list_kitty(ALICE, &dear, 700);
+
+		// Cada anúncio cabe no saldo de BOB sozinho, mas não os dois juntos.
+		assert_ok!(TemplateModule::sweep(RuntimeOrigin::signed(BOB), 2_000, 5));
+		System::assert_last_event(Event::Swept { buyer: BOB, count: 1, total_spent: 600 }.into());
+		assert_eq!(Kitties::<Test>::get(&cheap).unwrap().owner, BOB);
+		assert_eq!(Kitties::<Test>::get(&dear).unwrap().owner, ALICE);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 600);
+	});
+}
+
+#[test]
+fn keep_alive_buy_keeps_buyer_above_existential_deposit() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, INITIAL_BALANCE);
+
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Err(BuyError::InsufficientFunds));
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(BOB),
+				kitty_id.clone(),
+				ALICE,
+				INITIAL_BALANCE,
+				true,
+				None
+			),
+			Error::<Test>::WouldReapBuyer
+		);
+
+		// Sem saldo suficiente nem esvaziando a conta, o erro é o da moeda.
+		list_kitty(ALICE, &kitty_id, INITIAL_BALANCE + 1);
+		let result = TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			INITIAL_BALANCE + 1,
+			true,
+			None,
+		);
+		assert!(result.is_err());
+		assert_ne!(result.unwrap_err().error, Error::<Test>::WouldReapBuyer.into());
+
+		// Uma unidade abaixo do saldo mantém a conta viva e passa.
+		list_kitty(ALICE, &kitty_id, INITIAL_BALANCE - 1);
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id,
+			ALICE,
+			INITIAL_BALANCE - 1,
+			true,
+			None
+		));
+		assert_eq!(Balances::free_balance(BOB), 1);
+	});
+}
+
+#[test]
+fn expendable_buy_may_spend_whole_balance() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, INITIAL_BALANCE);
+
+		// `keep_alive` falso vale mesmo com `BuyPreservation` em `Preserve`.
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			INITIAL_BALANCE,
+			false,
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		assert_eq!(Balances::free_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn can_buy_follows_buy_preservation() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, INITIAL_BALANCE);
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Err(BuyError::InsufficientFunds));
+
+		BuyPreservation::set(Preservation::Expendable);
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Ok(INITIAL_BALANCE));
+	});
+}
+
+#[test]
+fn rejected_price_and_transfer_updates_leave_storage_untouched() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+
+		assert_noop!(
+			TemplateModule::set_price(RuntimeOrigin::signed(ALICE), filled_dna(9), Some(90), None),
+			Error::<Test>::NoKitty
+		);
+		assert_noop!(
+			TemplateModule::set_price(RuntimeOrigin::signed(BOB), kitty_id.clone(), Some(90), None),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			TemplateModule::set_price(
+				RuntimeOrigin::signed(ALICE),
+				kitty_id.clone(),
+				Some(0),
+				None
+			),
+			Error::<Test>::ZeroPrice
+		);
+
+		// A posse do destinatário falha depois de o dono já ter sido trocado no Kitty.
+		assert_ok!(TemplateModule::set_owned_limit(RuntimeOrigin::root(), BOB, Some(0)));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id.clone(), None),
+			Error::<Test>::TooManyOwned
+		);
+		let kitty = Kitties::<Test>::get(&kitty_id).unwrap();
+		assert_eq!((kitty.owner, kitty.price), (ALICE, Some(100)));
+	});
+}
+
+#[test]
+fn setting_identical_price_emits_no_event_and_is_free() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+		System::reset_events();
+
+		let post_info = TemplateModule::set_price(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			Some(100),
+			None,
+		)
+		.unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		assert!(pallet_events().is_empty());
+
+		let post_info = TemplateModule::set_price(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			Some(90),
+			None,
+		)
+		.unwrap();
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+		assert_eq!(
+			pallet_events(),
+			vec![Event::PriceSet { owner: ALICE, kitty_id, new_price: Some(90) }]
+		);
+	});
+}
+
+#[test]
+fn ensure_owner_returns_kitty_or_error() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_eq!(TemplateModule::ensure_owner(&kitty_id, &ALICE).unwrap().dna, kitty_id);
+
+		assert_eq!(
+			TemplateModule::ensure_owner(&kitty_id, &BOB).map(|kitty| kitty.dna),
+			Err(Error::<Test>::NotOwner.into())
+		);
+		assert_eq!(
+			TemplateModule::ensure_owner(&filled_dna(0xff), &ALICE).map(|kitty| kitty.dna),
+			Err(Error::<Test>::NoKitty.into())
+		);
+	});
+}
+
+#[test]
+fn rehome_only_moves_kitties_of_reaped_owners() {
+	new_test_ext().execute_with(|| {
+		let orphan = create_kitty(ALICE);
+		let held = create_kitty(BOB);
+		// ALICE esvazia a conta e é removida da cadeia, deixando o Kitty sem quem assine.
+		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(ALICE), CHARLIE, false));
+		assert!(!System::account_exists(&ALICE));
+
+		assert_noop!(
+			TemplateModule::rehome(RuntimeOrigin::root(), held, CHARLIE),
+			Error::<Test>::OwnerAccountExists
+		);
+		assert_noop!(
+			TemplateModule::rehome(RuntimeOrigin::signed(CHARLIE), orphan.clone(), CHARLIE),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(TemplateModule::rehome(RuntimeOrigin::root(), orphan.clone(), CHARLIE));
+		System::assert_last_event(
+			Event::Rehomed { kitty_id: orphan.clone(), from: ALICE, to: CHARLIE }.into(),
+		);
+		assert_eq!(Kitties::<Test>::get(&orphan).unwrap().owner, CHARLIE);
+		assert!(OwnedKitties::<Test>::contains_key(CHARLIE, &orphan));
+		assert_eq!(OwnedCount::<Test>::get(ALICE), 0);
+	});
+}
+
+#[test]
+fn claim_inactive_respects_threshold_boundary() {
+	new_test_ext().execute_with(|| {
+		// `create_kitty` é uma chamada de jogo, então registra atividade no bloco 1.
+		let kitty_id = create_kitty(ALICE);
+		assert_eq!(LastActivity::<Test>::get(ALICE), Some(1));
+
+		run_to_block(11);
+		assert_noop!(
+			TemplateModule::claim_inactive(RuntimeOrigin::root(), kitty_id.clone(), BOB),
+			Error::<Test>::OwnerStillActive
+		);
+
+		run_to_block(12);
+		assert_noop!(
+			TemplateModule::claim_inactive(RuntimeOrigin::signed(BOB), kitty_id.clone(), BOB),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::claim_inactive(RuntimeOrigin::root(), kitty_id.clone(), BOB));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		System::assert_last_event(Event::Claimed { kitty_id, from: ALICE, to: BOB }.into());
+	});
+}
+
+#[test]
+fn activity_resets_inactivity_window() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		let other = create_kitty(ALICE);
+
+		run_to_block(8);
+		list_kitty(ALICE, &other, 100);
+
+		run_to_block(12);
+		assert_noop!(
+			TemplateModule::claim_inactive(RuntimeOrigin::root(), kitty_id.clone(), BOB),
+			Error::<Test>::OwnerStillActive
+		);
+		run_to_block(19);
+		assert_ok!(TemplateModule::claim_inactive(RuntimeOrigin::root(), kitty_id, BOB));
+	});
+}
+
+#[test]
+fn root_calls_on_behalf_of_do_not_record_activity() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::create_kitty(RuntimeOrigin::root(), Some(BOB)));
+		let kitty_id = MintedKitties::get().pop().unwrap().1;
+		assert_ok!(TemplateModule::set_price(
+			RuntimeOrigin::root(),
+			kitty_id.clone(),
+			Some(100),
+			Some(BOB)
+		));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::root(), CHARLIE, kitty_id, Some(BOB)));
+		assert!(!LastActivity::<Test>::contains_key(BOB));
+	});
+}
+
+#[test]
+fn any_signed_call_blocks_claim_inactive() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		let bobs = create_kitty(BOB);
+
+		// `make_offer` não aceita `on_behalf_of`, mas também registra atividade.
+		run_to_block(8);
+		assert_ok!(TemplateModule::make_offer(RuntimeOrigin::signed(ALICE), bobs, 50, 100));
+		assert_eq!(LastActivity::<Test>::get(ALICE), Some(8));
+
+		run_to_block(12);
+		assert_noop!(
+			TemplateModule::claim_inactive(RuntimeOrigin::root(), kitty_id, BOB),
+			Error::<Test>::OwnerStillActive
+		);
+	});
+}
+
+#[test]
+fn config_constants_match_mock_config() {
+	new_test_ext().execute_with(|| {
+		MaxSupply::set(500);
+		MintDeposit::set(7);
+		AllowOpenMint::set(false);
+
+		assert_eq!(
+			TemplateModule::config_constants(),
+			ConfigConstants {
+				max_kitties_owned: 10,
+				max_supply: 500,
+				max_generation: u16::MAX,
+				max_name_length: 8,
+				max_memo: 16,
+				max_batch_size: 5,
+				max_listings_per_account: 10,
+				max_offers_per_kitty: 16,
+				max_price_points: 3,
+				mint_difficulty: 1,
+				allow_open_mint: false,
+				breeding_cooldown: 5,
+				feed_cooldown: 2,
+				burn_delay: 3,
+				price_update_cooldown: 0,
+				listing_decay: Permill::from_percent(10),
+				burn_refund_ratio: Permill::from_percent(80),
+				mint_deposit: 7,
+				accelerate_fee: 30,
+				min_sire_fee: 0,
+			}
+		);
+	});
+}
+
+#[test]
+fn market_summary_tracks_scripted_sequence() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			TemplateModule::market_summary(),
+			MarketSummary {
+				total_kitties: 0,
+				total_listed: 0,
+				floor_price: None,
+				total_volume: 0,
+				unique_owners: 0,
+			}
+		);
+
+		let kitty_1 = create_kitty(ALICE);
+		let kitty_2 = create_kitty(ALICE);
+		let kitty_3 = create_kitty(BOB);
+		list_kitty(ALICE, &kitty_1, 100);
+		list_kitty(ALICE, &kitty_2, 40);
+		list_kitty(BOB, &kitty_3, 70);
+
+		// CHARLIE compra dois Kitties e se torna dono; ALICE deixa de ser.
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_1,
+			ALICE,
+			100,
+			true,
+			None
+		));
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_2.clone(),
+			ALICE,
+			40,
+			true,
+			None
+		));
+		// BOB queima o seu único Kitty.
+		assert_ok!(TemplateModule::burn_kitty(RuntimeOrigin::signed(BOB), kitty_3));
+		list_kitty(CHARLIE, &kitty_2, 55);
+
+		assert_eq!(
+			TemplateModule::market_summary(),
+			MarketSummary {
+				total_kitties: 2,
+				total_listed: 1,
+				floor_price: Some(55),
+				total_volume: 140,
+				unique_owners: 1,
+			}
+		);
+	});
+}
+
+#[test]
+fn transfer_to_derived_sends_to_deterministic_account() {
+	new_test_ext().execute_with(|| {
+		let derived = TemplateModule::derived_account(&BOB, 7);
+		assert_eq!(derived, TemplateModule::derived_account(&BOB, 7));
+		assert_ne!(derived, TemplateModule::derived_account(&BOB, 8));
+		assert_ne!(derived, TemplateModule::derived_account(&CHARLIE, 7));
+		assert_ne!(derived, BOB);
+		// Mesma conta de `pallet_utility::derivative_account_id`.
+		let entropy = sp_io::hashing::blake2_256(&(b"modlpy/utilisuba", BOB, 7u16).encode());
+		let utility = u64::decode(&mut TrailingZeroInput::new(&entropy)).unwrap();
+		assert_eq!(derived, utility);
+
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::transfer_to_derived(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			7,
+			kitty_id.clone()
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, derived);
+		assert!(OwnedKitties::<Test>::contains_key(derived, &kitty_id));
+
+		// Só `parent` retira o Kitty da própria conta derivada.
+		assert_noop!(
+			TemplateModule::transfer_from_derived(
+				RuntimeOrigin::signed(CHARLIE),
+				7,
+				kitty_id.clone(),
+				CHARLIE
+			),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(TemplateModule::transfer_from_derived(
+			RuntimeOrigin::signed(BOB),
+			7,
+			kitty_id.clone(),
+			CHARLIE
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, CHARLIE);
+	});
+}
+
+#[test]
+fn transfer_validator_error_is_returned_verbatim() {
+	new_test_ext().execute_with(|| {
+		BlockedRecipient::set(Some(CHARLIE));
+		let kitty_id = create_kitty(ALICE);
+
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(ALICE), CHARLIE, kitty_id.clone(), None),
+			DispatchError::Other("recipient is not allowlisted")
+		);
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_id.clone(),
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+	});
+}
+
+#[test]
+fn rejecting_recipient_rolls_back_transfer() {
+	new_test_ext().execute_with(|| {
+		RejectingAccounts::set(vec![CHARLIE]);
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+
+		let rejected = DispatchError::Other("recipient rejected the Kitty");
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(ALICE), CHARLIE, kitty_id.clone(), None),
+			rejected
+		);
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(CHARLIE),
+				kitty_id.clone(),
+				ALICE,
+				100,
+				true,
+				None
+			),
+			rejected
+		);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE);
+
+		// Outros destinatários continuam aceitando.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id, None));
+	});
+}
+
+#[test]
+fn accept_best_offers_settles_highest_and_refunds_others() {
+	new_test_ext().execute_with(|| {
+		let kitty_1 = create_kitty(ALICE);
+		let kitty_2 = create_kitty(ALICE);
+		let no_offers = create_kitty(ALICE);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			kitty_1.clone(),
+			150,
+			10
+		));
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_1.clone(),
+			200,
+			10
+		));
+		assert_ok!(TemplateModule::make_offer(RuntimeOrigin::signed(BOB), kitty_2.clone(), 90, 10));
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_2.clone(),
+			60,
+			10
+		));
+
+		assert_ok!(TemplateModule::accept_best_offers(
+			RuntimeOrigin::signed(ALICE),
+			BoundedVec::truncate_from(vec![kitty_1.clone(), kitty_2.clone(), no_offers.clone()])
+		));
+
+		assert_eq!(Kitties::<Test>::get(&kitty_1).unwrap().owner, CHARLIE);
+		assert_eq!(Kitties::<Test>::get(&kitty_2).unwrap().owner, BOB);
+		assert_eq!(Kitties::<Test>::get(&no_offers).unwrap().owner, ALICE);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 290);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 90);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE - 200);
+		assert_eq!(Offers::<Test>::iter().count(), 0);
+
+		let accepted: Vec<_> = pallet_events()
+			.into_iter()
+			.filter(|event| matches!(event, Event::OfferAccepted { .. }))
+			.collect();
+		assert_eq!(
+			accepted,
+			vec![
+				Event::OfferAccepted { kitty_id: kitty_1, bidder: CHARLIE, amount: 200 },
+				Event::OfferAccepted { kitty_id: kitty_2, bidder: BOB, amount: 90 },
+			]
+		);
+	});
+}
+
+#[test]
+fn accept_best_offers_ignores_expired_offers() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			500,
+			3
+		));
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_id.clone(),
+			100,
+			10
+		));
+
+		run_to_block(3);
+		assert_ok!(TemplateModule::accept_best_offers(
+			RuntimeOrigin::signed(ALICE),
+			BoundedVec::truncate_from(vec![kitty_id.clone()])
+		));
+
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, CHARLIE);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn accept_best_offers_fails_atomically_on_foreign_kitty() {
+	new_test_ext().execute_with(|| {
+		let mine = create_kitty(ALICE);
+		let foreign = create_kitty(BOB);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			mine.clone(),
+			100,
+			10
+		));
+
+		assert_noop!(
+			TemplateModule::accept_best_offers(
+				RuntimeOrigin::signed(ALICE),
+				BoundedVec::truncate_from(vec![mine, foreign])
+			),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_weight_comes_from_weight_info() {
+	let max_offers: u32 = <Test as crate::Config>::MaxOffersPerKitty::get();
+	assert_eq!(
+		crate::Call::<Test>::buy_kitty {
+			kitty_id: filled_dna(0),
+			expected_seller: ALICE,
+			max_price: 0,
+			keep_alive: true,
+			on_behalf_of: None
+		}
+		.get_dispatch_info()
+		.weight,
+		<() as WeightInfo>::buy_kitty().saturating_add(TemplateModule::batch_weight(max_offers))
+	);
+}
+
+#[test]
+fn breed_kitty_weight_comes_from_weight_info() {
+	assert_eq!(
+		crate::Call::<Test>::breed_kitty {
+			parent_1: filled_dna(0),
+			parent_2: filled_dna(1),
+			on_behalf_of: None
+		}
+		.get_dispatch_info()
+		.weight,
+		<() as WeightInfo>::breed_kitty()
+	);
+}
+
+#[test]
+fn transfer_keeps_relative_order_of_remaining_kitties() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..5 {
+			create_kitty(ALICE);
+		}
+		let before = TemplateModule::owned_ids(&ALICE);
+
+		for kitty_id in [before[0].clone(), before[2].clone()] {
+			assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id, None));
+		}
+		assert_eq!(
+			TemplateModule::owned_ids(&ALICE),
+			vec![before[1].clone(), before[3].clone(), before[4].clone()]
+		);
+	});
+}
+
+#[test]
+fn force_set_owner_repairs_corrupted_ownership() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		create_kitty(CHARLIE);
+		// Corrompe a posse: ALICE perde o Kitty das listas e CHARLIE o recebe por engano.
+		OwnedKitties::<Test>::remove(ALICE, &kitty_id);
+		OwnedKitties::<Test>::insert(CHARLIE, &kitty_id, ());
+		OwnedCount::<Test>::insert(CHARLIE, 5);
+		assert!(TemplateModule::do_try_state().is_err());
+
+		assert_noop!(
+			TemplateModule::force_set_owner(RuntimeOrigin::signed(ALICE), kitty_id.clone(), BOB),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::force_set_owner(RuntimeOrigin::root(), kitty_id.clone(), BOB));
+		System::assert_last_event(
+			Event::OwnerRepaired { kitty_id: kitty_id.clone(), old_owner: ALICE, new_owner: BOB }
+				.into(),
+		);
+
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		assert_eq!(TemplateModule::accounts_holding(&kitty_id), vec![BOB]);
+		assert_eq!(OwnedCount::<Test>::get(CHARLIE), 1);
+		assert!(!OwnedCount::<Test>::contains_key(ALICE));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn force_set_owner_is_idempotent_on_consistent_state() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+
+		for _ in 0..2 {
+			assert_ok!(TemplateModule::force_set_owner(
+				RuntimeOrigin::root(),
+				kitty_id.clone(),
+				ALICE
+			));
+			assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().price, Some(100));
+			assert_eq!(OwnedCount::<Test>::get(ALICE), 2);
+			assert_ok!(TemplateModule::do_try_state());
+		}
+	});
+}
+
+#[test]
+fn force_set_owner_cancels_pending_burn_of_old_owner() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::request_burn(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+
+		assert_ok!(TemplateModule::force_set_owner(RuntimeOrigin::root(), kitty_id.clone(), BOB));
+		assert_eq!(PendingBurns::<Test>::get(&kitty_id), None);
+
+		run_to_block(10);
+		assert_noop!(
+			TemplateModule::finalize_burn(RuntimeOrigin::signed(CHARLIE), kitty_id.clone()),
+			Error::<Test>::NoPendingBurn
+		);
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(BOB),
+			CHARLIE,
+			kitty_id.clone(),
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, CHARLIE);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn transfer_with_price_lists_kitty_for_new_owner() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+		System::reset_events();
+
+		assert_ok!(TemplateModule::transfer_with_price(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_id.clone(),
+			250
+		));
+
+		let kitty = Kitties::<Test>::get(&kitty_id).unwrap();
+		assert_eq!((kitty.owner, kitty.price), (BOB, Some(250)));
+		assert_eq!(ListingCount::<Test>::get(BOB), Some(1));
+		assert_eq!(ListingCount::<Test>::get(ALICE), None);
+		assert_eq!(
+			pallet_events(),
+			vec![
+				Event::Transferred {
+					from: ALICE,
+					to: BOB,
+					kitty_id: kitty_id.clone(),
+					reason: TransferReason::Direct,
+					from_remaining: 0,
+					to_total: 1,
+				},
+				Event::PriceSet { owner: BOB, kitty_id, new_price: Some(250) },
+			]
+		);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn purge_account_only_works_without_kitties_and_keeps_admin_state() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::set_owned_limit(RuntimeOrigin::root(), ALICE, Some(5)));
+
+		assert_noop!(
+			TemplateModule::purge_account(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NonEmptyAccount
+		);
+
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(ALICE), BOB, kitty_id, None));
+		assert_ok!(TemplateModule::purge_account(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(Event::AccountPurged { who: ALICE }.into());
+
+		assert!(!LastActivity::<Test>::contains_key(ALICE));
+		// O limite definido pela origem root e o nonce de criação sobrevivem à limpeza.
+		assert_eq!(TemplateModule::owned_capacity(&ALICE), (0, 5));
+		assert_eq!(MintNonce::<Test>::get(ALICE), 1);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn owned_ids_matches_ownership_map() {
+	new_test_ext().execute_with(|| {
+		assert!(TemplateModule::owned_ids(&ALICE).is_empty());
+
+		let kitty_ids = vec![create_kitty(ALICE), create_kitty(ALICE), create_kitty(ALICE)];
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			kitty_ids[0].clone(),
+			None
+		));
+
+		let owned = TemplateModule::owned_ids(&ALICE);
+		assert_eq!(owned, OwnedKitties::<Test>::iter_key_prefix(ALICE).collect::<Vec<_>>());
+		assert_eq!(owned.len(), 2);
+		assert_eq!(OwnedCount::<Test>::get(ALICE), 2);
+	});
+}
+
+#[test]
+fn buy_kitty_aborts_when_seller_changed() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+
+		// O Kitty troca de dono e volta a ser anunciado antes de a compra chegar.
+		assert_ok!(TemplateModule::transfer(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE,
+			kitty_id.clone(),
+			None
+		));
+		list_kitty(CHARLIE, &kitty_id, 100);
+
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(BOB),
+				kitty_id.clone(),
+				ALICE,
+				100,
+				true,
+				None
+			),
+			Error::<Test>::SellerChanged
+		);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, CHARLIE);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id,
+			CHARLIE,
+			100,
+			true,
+			None
+		));
+	});
+}
+
+#[test]
+fn buy_kitty_refunds_open_offers() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_id.clone(),
+			300,
+			10
+		));
+
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			100,
+			true,
+			None
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, BOB);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE);
+		assert_eq!(Balances::free_balance(TemplateModule::account_id()), 0);
+		assert!(!Offers::<Test>::contains_key(&kitty_id, CHARLIE));
+		assert!(pallet_events()
+			.contains(&Event::OfferRejected { kitty_id: kitty_id.clone(), bidder: CHARLIE }));
+
+		// A oferta vencida pela compra não pode mais ser aceita pelo novo dono.
+		assert_noop!(
+			TemplateModule::accept_offer(RuntimeOrigin::signed(BOB), kitty_id, CHARLIE),
+			Error::<Test>::NoOffer
+		);
+	});
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn accepted_offer_invalidates_listing_for_buyers() {
 	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		System::set_block_number(1);
-		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(Something::<Test>::get(), Some(42));
-		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
+		let kitty_id = create_kitty(ALICE);
+		list_kitty(ALICE, &kitty_id, 100);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			kitty_id.clone(),
+			300,
+			10
+		));
+
+		assert_ok!(TemplateModule::accept_offer(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			CHARLIE
+		));
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().price, None);
+		assert_eq!(ListingCount::<Test>::get(ALICE), None);
+
+		// A compra que chega depois falha sem mover fundos nem o Kitty.
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(BOB),
+				kitty_id.clone(),
+				ALICE,
+				100,
+				true,
+				None
+			),
+			Error::<Test>::SellerChanged
+		);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().owner, CHARLIE);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 300);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE - 300);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn price_and_name_accessors_fall_back_to_defaults() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_eq!(TemplateModule::price_or_zero(&kitty_id), 0);
+		assert!(TemplateModule::name_or_empty(&kitty_id).is_empty());
+
+		list_kitty(ALICE, &kitty_id, 70);
+		assert_ok!(TemplateModule::set_name(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			name(b"Tom")
+		));
+		assert_eq!(TemplateModule::price_or_zero(&kitty_id), 70);
+		assert_eq!(TemplateModule::name_or_empty(&kitty_id), b"Tom".to_vec());
+
+		assert_eq!(TemplateModule::price_or_zero(&filled_dna(9)), 0);
+		assert!(TemplateModule::name_or_empty(&filled_dna(9)).is_empty());
+	});
+}
+
+#[test]
+fn supply_cap_event_fires_on_final_mint_only() {
+	new_test_ext().execute_with(|| {
+		MaxSupply::set(3);
+		let cap_events = || {
+			pallet_events()
+				.into_iter()
+				.filter(|event| matches!(event, Event::SupplyCapReached { .. }))
+				.count()
+		};
+
+		create_kitty(ALICE);
+		create_kitty(BOB);
+		assert_eq!(cap_events(), 0);
+
+		create_kitty(CHARLIE);
+		System::assert_last_event(Event::SupplyCapReached { total: 3 }.into());
+		assert_noop!(
+			TemplateModule::create_kitty(RuntimeOrigin::signed(ALICE), None),
+			Error::<Test>::TooManyKitties
+		);
+		assert_eq!(cap_events(), 1);
+	});
+}
+
+#[test]
+fn burn_splits_mint_deposit_between_owner_and_fee_account() {
+	new_test_ext().execute_with(|| {
+		MintDeposit::set(100);
+		let kitty_id = create_kitty(ALICE);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 100);
+		assert_eq!(Balances::free_balance(TemplateModule::account_id()), 100);
+
+		assert_ok!(TemplateModule::burn_kitty(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+		System::assert_last_event(
+			Event::DepositRefunded { owner: ALICE, kitty_id, amount: 80 }.into(),
+		);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 20);
+		assert_eq!(Balances::free_balance(TemplateModule::fee_account()), 20);
+		assert_eq!(Balances::free_balance(TemplateModule::account_id()), 0);
+	});
+}
+
+#[test]
+fn burn_without_deposit_moves_no_funds() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		MintDeposit::set(100);
+
+		assert_ok!(TemplateModule::burn_kitty(RuntimeOrigin::signed(ALICE), kitty_id.clone()));
+		System::assert_last_event(Event::Burned { owner: ALICE, kitty_id }.into());
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		assert_eq!(Balances::free_balance(TemplateModule::fee_account()), 0);
+	});
+}
+
+#[test]
+fn fees_accrue_to_fee_account_until_root_withdraws_them() {
+	new_test_ext().execute_with(|| {
+		MintDeposit::set(100);
+		let kitty_id = create_kitty(ALICE);
+		assert_ok!(TemplateModule::burn_kitty(RuntimeOrigin::signed(ALICE), kitty_id));
+		let fees = TemplateModule::fee_account();
+		assert_ne!(fees, TemplateModule::account_id());
+		assert_eq!(Balances::free_balance(fees), 20);
+
+		assert_noop!(
+			TemplateModule::withdraw_fees(RuntimeOrigin::signed(ALICE), ALICE, 20),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::withdraw_fees(RuntimeOrigin::root(), BOB, 15));
+		System::assert_last_event(Event::FeesWithdrawn { to: BOB, amount: 15 }.into());
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + 15);
+		assert_eq!(Balances::free_balance(fees), 5);
+		assert!(TemplateModule::withdraw_fees(RuntimeOrigin::root(), BOB, 6).is_err());
+	});
+}
+
+/// DNA com os bytes de características definidos e o resto zerado.
+fn traits_dna(species: u8, gender: u8, color: [u8; 3], pattern: u8) -> [u8; 32] {
+	let mut dna = [0u8; 32];
+	dna[..6].copy_from_slice(&[species, gender, color[0], color[1], color[2], pattern]);
+	dna
+}
+
+#[test]
+fn traits_decode_from_dna_bytes() {
+	for byte in 0..=u8::MAX {
+		let dna = traits_dna(byte, byte, [byte, 0, 0], byte);
+		assert_eq!(species_of(&dna), byte % 8);
+		assert_eq!(color_of(&dna), [byte, 0, 0]);
+		assert_eq!(gender_of(&dna), if byte % 2 == 0 { Gender::Male } else { Gender::Female });
+		let pattern =
+			[Pattern::Solid, Pattern::Striped, Pattern::Spotted, Pattern::Tabby][byte as usize % 4];
+		assert_eq!(pattern_of(&dna), pattern);
+	}
+
+	let dna = traits_dna(13, 7, [255, 128, 1], 6);
+	assert_eq!(
+		decode_traits(&dna),
+		KittyTraits {
+			species: 5,
+			gender: Gender::Female,
+			color: [255, 128, 1],
+			pattern: Pattern::Spotted
+		}
+	);
+	// Bytes fora das posições de características não mudam nada.
+	let mut noisy = dna;
+	noisy[6..].fill(0xff);
+	assert_eq!(decode_traits(&noisy), decode_traits(&dna));
+}
+
+/// Anuncia o Kitty de ALICE em leilão que começa em 1000 e cai 10% a cada 100 blocos.
+fn start_step_auction(kitty_id: &DnaOf<Test>) {
+	assert_ok!(TemplateModule::start_step_auction(
+		RuntimeOrigin::signed(ALICE),
+		kitty_id.clone(),
+		1_000,
+		Permill::from_percent(10),
+		100
+	));
+}
+
+#[test]
+fn step_price_drops_exactly_at_step_boundaries() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_eq!(TemplateModule::current_step_price(&kitty_id), None);
+		start_step_auction(&kitty_id);
+
+		for (block, price) in [(1, 1_000), (100, 1_000), (101, 900), (200, 900), (201, 810)] {
+			System::set_block_number(block);
+			assert_eq!(TemplateModule::current_step_price(&kitty_id), Some(price));
+		}
+
+		// O preço nunca chega a zero.
+		System::set_block_number(1_000_001);
+		assert_eq!(TemplateModule::current_step_price(&kitty_id), Some(1));
+	});
+}
+
+#[test]
+fn buy_kitty_pays_current_step_price() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		start_step_auction(&kitty_id);
+		System::set_block_number(201);
+
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Ok(810));
+		assert_noop!(
+			TemplateModule::buy_kitty(
+				RuntimeOrigin::signed(BOB),
+				kitty_id.clone(),
+				ALICE,
+				809,
+				true,
+				None
+			),
+			Error::<Test>::MaxPriceTooLow
+		);
+		assert_ok!(TemplateModule::buy_kitty(
+			RuntimeOrigin::signed(BOB),
+			kitty_id.clone(),
+			ALICE,
+			900,
+			true,
+			None
+		));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 810);
+		assert_eq!(TemplateModule::current_step_price(&kitty_id), None);
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn set_price_ends_step_auction_and_decay_skips_it() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
+		let kitty_id = create_kitty(ALICE);
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			TemplateModule::start_step_auction(
+				RuntimeOrigin::signed(ALICE),
+				kitty_id.clone(),
+				1_000,
+				Permill::from_percent(10),
+				0
+			),
+			Error::<Test>::ZeroStepInterval
 		);
+		start_step_auction(&kitty_id);
+
+		run_to_block(3);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().price, Some(1_000));
+
+		list_kitty(ALICE, &kitty_id, 500);
+		assert_eq!(TemplateModule::current_step_price(&kitty_id), None);
+		assert_eq!(TemplateModule::can_buy(&BOB, &kitty_id), Ok(500));
+	});
+}
+
+#[test]
+fn price_queries_report_current_step_price() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		start_step_auction(&kitty_id);
+		System::set_block_number(201);
+
+		assert_eq!(TemplateModule::price_or_zero(&kitty_id), 810);
+		assert_eq!(TemplateModule::listed_value(&ALICE), 810);
+		assert_eq!(TemplateModule::listings_of(&ALICE, 0, 10), vec![(kitty_id.clone(), 810)]);
+		assert_eq!(TemplateModule::floor_listing(), Some((kitty_id, 810)));
+		assert_eq!(TemplateModule::market_summary().floor_price, Some(810));
+	});
+}
+
+#[test]
+fn set_price_to_unchanged_price_still_ends_step_auction() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		start_step_auction(&kitty_id);
+		System::set_block_number(201);
+
+		assert_ok!(TemplateModule::set_price(
+			RuntimeOrigin::signed(ALICE),
+			kitty_id.clone(),
+			Some(1_000),
+			None
+		));
+		assert_eq!(TemplateModule::current_step_price(&kitty_id), None);
+		assert_eq!(TemplateModule::price_or_zero(&kitty_id), 1_000);
+		System::assert_last_event(
+			Event::PriceSet { owner: ALICE, kitty_id, new_price: Some(1_000) }.into(),
+		);
+	});
+}
+
+#[test]
+fn minted_kitty_has_current_format_version() {
+	new_test_ext().execute_with(|| {
+		let kitty_id = create_kitty(ALICE);
+		assert_eq!(Kitties::<Test>::get(&kitty_id).unwrap().format_version, KITTY_FORMAT_VERSION);
+	});
+}
+
+#[test]
+fn migration_v1_stamps_existing_kitties() {
+	use crate::migrations::v1::{self, MigrateV0ToV1, OldKitty};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let kitty_id = filled_dna(7);
+		let old = OldKitty::<Test> {
+			dna: kitty_id.clone(),
+			owner: ALICE,
+			price: Some(50),
+			species: 7,
+			cooldown_until: 0,
+			level: 3,
+			last_fed: 0,
+			transfer_locked_until: None,
+		};
+		frame_support::storage::unhashed::put(&Kitties::<Test>::hashed_key_for(&kitty_id), &old);
+		StorageVersion::new(0).put::<TemplateModule>();
+
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		let kitty = v1::Kitties::<Test>::get(&kitty_id).unwrap();
+		assert_eq!((kitty.format_version, kitty.level, kitty.price), (1, 3, Some(50)));
+		assert_eq!(TemplateModule::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+#[test]
+fn migration_v1_weight_counts_translated_kitties() {
+	use crate::migrations::v1::{self, InnerMigrateV0ToV1, OldKitty};
+	use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+	new_test_ext().execute_with(|| {
+		for byte in 1..=3u8 {
+			let old = OldKitty::<Test> {
+				dna: filled_dna(byte),
+				owner: ALICE,
+				price: None,
+				species: byte,
+				cooldown_until: 0,
+				level: 0,
+				last_fed: 0,
+				transfer_locked_until: None,
+			};
+			let key = Kitties::<Test>::hashed_key_for(&filled_dna(byte));
+			frame_support::storage::unhashed::put(&key, &old);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		let state = InnerMigrateV0ToV1::<Test>::pre_upgrade().unwrap();
+		let weight = InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		InnerMigrateV0ToV1::<Test>::post_upgrade(state).unwrap();
+
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		assert_eq!(weight, db.reads_writes(3, 3));
+		assert_eq!(v1::Kitties::<Test>::iter_values().filter(|k| k.format_version == 1).count(), 3);
+	});
+}
+
+#[test]
+fn migration_v2_moves_ownership_lists_into_double_map() {
+	use crate::migrations::v2::{KittiesOwned, MigrateV1ToV2};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let kitty_ids = [filled_dna(1), filled_dna(2)];
+		KittiesOwned::<Test>::insert(ALICE, BoundedVec::truncate_from(kitty_ids.to_vec()));
+		KittiesOwned::<Test>::insert(BOB, BoundedVec::truncate_from(vec![filled_dna(3)]));
+		StorageVersion::new(1).put::<TemplateModule>();
+
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(KittiesOwned::<Test>::iter().count(), 0);
+		assert_eq!(OwnedCount::<Test>::get(ALICE), 2);
+		assert_eq!(OwnedCount::<Test>::get(BOB), 1);
+		assert!(kitty_ids.iter().all(|id| OwnedKitties::<Test>::contains_key(ALICE, id)));
+		assert!(OwnedKitties::<Test>::contains_key(BOB, &filled_dna(3)));
+		assert_eq!(TemplateModule::on_chain_storage_version(), StorageVersion::new(2));
+	});
+}
+
+#[test]
+fn migration_v3_adds_empty_equipment_slot() {
+	use crate::migrations::{
+		v1::{self, KittyV1},
+		v3::MigrateV2ToV3,
+	};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let kitty_id = filled_dna(7);
+		let old = KittyV1::<Test> {
+			dna: kitty_id.clone(),
+			owner: ALICE,
+			price: Some(50),
+			species: 7,
+			cooldown_until: 0,
+			level: 3,
+			last_fed: 0,
+			transfer_locked_until: None,
+			format_version: 1,
+		};
+		v1::Kitties::<Test>::insert(&kitty_id, old);
+		StorageVersion::new(2).put::<TemplateModule>();
+
+		MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+		let kitty = Kitties::<Test>::get(&kitty_id).unwrap();
+		assert_eq!((kitty.format_version, kitty.level, kitty.equipped), (2, 3, None));
+		assert_eq!(TemplateModule::on_chain_storage_version(), StorageVersion::new(3));
 	});
 }
+
+/// Orçamento, em bytes, do `Kitty` codificado com os tipos do mock.
+///
+/// Cada Kitty fica inteiro numa entrada de `Kitties`, e tudo que lê ou grava um Kitty paga por
+/// esse tamanho, inclusive o PoV de cada compra, transferência e cruzamento. Um campo novo que
+/// estoure o orçamento deve ir para um mapa próprio, como `KittyNames`, ou vir acompanhado de
+/// um aumento deliberado deste valor.
+const KITTY_ENCODED_BUDGET: usize = 96;
+
+#[test]
+fn kitty_max_encoded_len_stays_within_budget() {
+	// Com AccountId, Balance e BlockNumber `u64`, e ItemId `u32`.
+	let expected = 32 // dna, com `DnaLength` 32
+		+ 8 // owner
+		+ (1 + 8) // price
+		+ 1 // species
+		+ 8 // cooldown_until
+		+ 1 // level
+		+ 8 // last_fed
+		+ (1 + 8) // transfer_locked_until
+		+ 1 // format_version
+		+ (1 + 4); // equipped
+	assert_eq!(Kitty::<Test>::max_encoded_len(), expected);
+	assert!(
+		Kitty::<Test>::max_encoded_len() <= KITTY_ENCODED_BUDGET,
+		"Kitty cresceu além de KITTY_ENCODED_BUDGET; veja a justificativa do orçamento",
+	);
+}
+
+#[test]
+fn dna_length_sets_the_size_of_kitty_ids() {
+	for len in [16, 64] {
+		DnaLength::set(len);
+		new_test_ext().execute_with(|| {
+			let parent_1 = create_kitty(ALICE);
+			let parent_2 = create_kitty(ALICE);
+			let child = breed(ALICE, &parent_1, &parent_2);
+
+			for kitty_id in [&parent_1, &parent_2, &child] {
+				assert_eq!(kitty_id.len(), len as usize);
+				assert_eq!(kitty_id.encode().len(), len as usize);
+				assert_eq!(&Kitties::<Test>::get(kitty_id).unwrap().dna, kitty_id);
+			}
+			// Os outros campos do Kitty somam 50 bytes, como em
+			// `kitty_max_encoded_len_stays_within_budget`.
+			assert_eq!(Kitty::<Test>::max_encoded_len(), 50 + len as usize);
+			assert_ok!(TemplateModule::do_try_state());
+		});
+	}
+}
+
+#[test]
+fn dna_of_32_bytes_encodes_like_a_byte_array() {
+	let bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+	let dna = DnaOf::<Test>::try_from(bytes.to_vec()).unwrap();
+	assert_eq!(dna.encode(), bytes.encode());
+	assert_eq!(DnaOf::<Test>::decode(&mut &bytes[..]).unwrap(), dna);
+	assert!(DnaOf::<Test>::decode(&mut &bytes[..31]).is_err());
+	assert_eq!(DnaOf::<Test>::try_from(vec![0; 31]), Err(vec![0; 31]));
+
+	// Os 32 primeiros bytes são o `blake2_256` da semente, como antes de o tamanho ser
+	// configurável.
+	let seed = (ALICE, 7u64);
+	assert_eq!(&DnaOf::<Test>::hash_of(&seed)[..], &sp_io::hashing::blake2_256(&seed.encode()));
+}
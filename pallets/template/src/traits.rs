@@ -0,0 +1,77 @@
+//! Características de um Kitty derivadas do DNA.
+//!
+//! Funções puras, sem acesso ao armazenamento, que servem tanto ao pallet quanto a ferramentas
+//! fora da cadeia.
+//!
+//! As características vêm dos bytes 0 a 5, então o DNA precisa ter pelo menos `MIN_DNA_LENGTH`
+//! bytes.
+
+use crate::SPECIES_COUNT;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Gênero de um Kitty, decidido pela paridade do byte 1 do DNA.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+/// Padrão da pelagem de um Kitty, decidido pelo byte 5 do DNA.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub enum Pattern {
+    Solid,
+    Striped,
+    Spotted,
+    Tabby,
+}
+
+/// Todas as características de um Kitty, retornadas por `decode_traits`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub struct KittyTraits {
+    pub species: u8,
+    pub gender: Gender,
+    /// Cor RGB, copiada dos bytes 2 a 4 do DNA.
+    pub color: [u8; 3],
+    pub pattern: Pattern,
+}
+
+/// Espécie codificada no byte 0 do DNA, menor que `SPECIES_COUNT`.
+pub fn species_of(dna: &[u8]) -> u8 {
+    dna[0] % SPECIES_COUNT
+}
+
+/// Gênero codificado no byte 1 do DNA: par é macho, ímpar é fêmea.
+pub fn gender_of(dna: &[u8]) -> Gender {
+    if dna[1] % 2 == 0 {
+        Gender::Male
+    } else {
+        Gender::Female
+    }
+}
+
+/// Cor RGB codificada nos bytes 2 a 4 do DNA.
+pub fn color_of(dna: &[u8]) -> [u8; 3] {
+    [dna[2], dna[3], dna[4]]
+}
+
+/// Padrão codificado no byte 5 do DNA.
+pub fn pattern_of(dna: &[u8]) -> Pattern {
+    match dna[5] % 4 {
+        0 => Pattern::Solid,
+        1 => Pattern::Striped,
+        2 => Pattern::Spotted,
+        _ => Pattern::Tabby,
+    }
+}
+
+/// Decodifica todas as características do DNA de uma vez.
+pub fn decode_traits(dna: &[u8]) -> KittyTraits {
+    KittyTraits {
+        species: species_of(dna),
+        gender: gender_of(dna),
+        color: color_of(dna),
+        pattern: pattern_of(dna),
+    }
+}
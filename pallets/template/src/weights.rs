@@ -1,29 +1,15 @@
-
-//! Autogenerated weights for pallet_template
+//! Weights for pallet_template
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ../../target/release/node-template
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_template
-// --extrinsic
-// *
-// --steps=50
-// --repeat=20
-// --wasm-execution=compiled
-// --output
-// pallets/template/src/weights.rs
-// --template
-// ../../.maintain/frame-weight-template.hbs
+//! Os valores abaixo foram estimados à mão a partir da estrutura das chamadas e devem ser
+//! substituídos pela saída do benchmark CLI ao rodar:
+//!
+//! ```text
+//! ../../target/release/solochain-template-node benchmark pallet \
+//!     --chain dev --pallet pallet_template --extrinsic '*' \
+//!     --steps=50 --repeat=20 --wasm-execution=compiled \
+//!     --output pallets/template/src/weights.rs \
+//!     --template ../../.maintain/frame-weight-template.hbs
+//! ```
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -34,57 +20,121 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_template.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn transfer() -> Weight;
+	fn buy_kitty() -> Weight;
+	fn breed_kitty() -> Weight;
+	fn cleanup_expired_offer() -> Weight;
 }
 
 /// Weights for pallet_template using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+	/// Storage: TemplateModule Kitties (r:1 w:1)
+	/// Storage: TemplateModule OwnedKitties (r:1 w:2)
+	/// Storage: TemplateModule OwnedCount (r:2 w:2)
+	/// Storage: TemplateModule OwnedLimits (r:1 w:0)
+	/// Storage: TemplateModule SireListings (r:0 w:1)
+	/// Storage: TemplateModule Reservations (r:0 w:1)
+	fn transfer() -> Weight {
+		Weight::from_parts(25_000_000, 4667)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Kitties (r:1 w:1)
+	/// Storage: TemplateModule PendingBurns (r:1 w:0)
+	/// Storage: TemplateModule Reservations (r:1 w:1)
+	/// Storage: TemplateModule PrivateSales (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: TemplateModule OwnedKitties (r:1 w:2)
+	/// Storage: TemplateModule OwnedCount (r:2 w:2)
+	/// Storage: TemplateModule OwnedLimits (r:1 w:0)
+	/// Storage: TemplateModule ListingCount (r:1 w:1)
+	/// Storage: TemplateModule ListedCount (r:1 w:1)
+	/// Storage: TemplateModule OwnerCount (r:1 w:1)
+	/// Storage: TemplateModule LastSalePrice (r:0 w:1)
+	/// Storage: TemplateModule TotalVolume (r:1 w:1)
+	/// Storage: TemplateModule SireListings (r:0 w:1)
+	/// Storage: TemplateModule Approvals (r:0 w:1)
+	fn buy_kitty() -> Weight {
+		Weight::from_parts(72_000_000, 7986)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+	/// Storage: TemplateModule Kitties (r:3 w:3)
+	/// Storage: TemplateModule CountForKitties (r:1 w:1)
+	/// Storage: TemplateModule OwnedKitties (r:0 w:1)
+	/// Storage: TemplateModule OwnedCount (r:1 w:1)
+	/// Storage: TemplateModule OwnedLimits (r:1 w:0)
+	/// Storage: TemplateModule SpeciesIndex (r:0 w:1)
+	/// Storage: TemplateModule LastActivity (r:0 w:1)
+	fn breed_kitty() -> Weight {
+		Weight::from_parts(38_000_000, 6352)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: TemplateModule Offers (r:1 w:1)
+	/// Storage: TemplateModule OfferCount (r:1 w:1)
+	/// Storage: TemplateModule OfferedTotal (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn cleanup_expired_offer() -> Weight {
+		Weight::from_parts(33_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	/// Storage: TemplateModule Kitties (r:1 w:1)
+	/// Storage: TemplateModule OwnedKitties (r:1 w:2)
+	/// Storage: TemplateModule OwnedCount (r:2 w:2)
+	/// Storage: TemplateModule OwnedLimits (r:1 w:0)
+	/// Storage: TemplateModule SireListings (r:0 w:1)
+	/// Storage: TemplateModule Reservations (r:0 w:1)
+	fn transfer() -> Weight {
+		Weight::from_parts(25_000_000, 4667)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule Kitties (r:1 w:1)
+	/// Storage: TemplateModule PendingBurns (r:1 w:0)
+	/// Storage: TemplateModule Reservations (r:1 w:1)
+	/// Storage: TemplateModule PrivateSales (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: TemplateModule OwnedKitties (r:1 w:2)
+	/// Storage: TemplateModule OwnedCount (r:2 w:2)
+	/// Storage: TemplateModule OwnedLimits (r:1 w:0)
+	/// Storage: TemplateModule ListingCount (r:1 w:1)
+	/// Storage: TemplateModule ListedCount (r:1 w:1)
+	/// Storage: TemplateModule OwnerCount (r:1 w:1)
+	/// Storage: TemplateModule LastSalePrice (r:0 w:1)
+	/// Storage: TemplateModule TotalVolume (r:1 w:1)
+	/// Storage: TemplateModule SireListings (r:0 w:1)
+	/// Storage: TemplateModule Approvals (r:0 w:1)
+	fn buy_kitty() -> Weight {
+		Weight::from_parts(72_000_000, 7986)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: TemplateModule Kitties (r:3 w:3)
+	/// Storage: TemplateModule CountForKitties (r:1 w:1)
+	/// Storage: TemplateModule OwnedKitties (r:0 w:1)
+	/// Storage: TemplateModule OwnedCount (r:1 w:1)
+	/// Storage: TemplateModule OwnedLimits (r:1 w:0)
+	/// Storage: TemplateModule SpeciesIndex (r:0 w:1)
+	/// Storage: TemplateModule LastActivity (r:0 w:1)
+	fn breed_kitty() -> Weight {
+		Weight::from_parts(38_000_000, 6352)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	/// Storage: TemplateModule Offers (r:1 w:1)
+	/// Storage: TemplateModule OfferCount (r:1 w:1)
+	/// Storage: TemplateModule OfferedTotal (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn cleanup_expired_offer() -> Weight {
+		Weight::from_parts(33_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 }
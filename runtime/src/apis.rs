@@ -42,10 +42,14 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, InherentDataExt, Nonce,
+	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TemplateModule,
+	TransactionPayment, VERSION,
 };
 
+/// DNA of a Kitty, which is also its id in the Kitties runtime API.
+type KittyId = pallet_template::DnaOf<Runtime>;
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -214,6 +218,106 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_template::runtime_api::KittiesApi<Block, AccountId, Balance, BlockNumber, KittyId>
+		for Runtime
+	{
+		fn kitties_of_species(species: u8, start: u32, limit: u32) -> Vec<KittyId> {
+			TemplateModule::kitties_of_species(species, start, limit)
+		}
+
+		fn cooldown_remaining(kitty_id: KittyId) -> Option<BlockNumber> {
+			TemplateModule::cooldown_remaining(&kitty_id)
+		}
+
+		fn can_buy(
+			buyer: AccountId,
+			kitty_id: KittyId,
+		) -> Result<Balance, pallet_template::BuyError> {
+			TemplateModule::can_buy(&buyer, &kitty_id)
+		}
+
+		fn can_breed(
+			parent_1: KittyId,
+			parent_2: KittyId,
+			who: AccountId,
+		) -> Result<(), pallet_template::BreedError> {
+			TemplateModule::can_breed(&parent_1, &parent_2, &who)
+		}
+
+		fn owned_capacity(account: AccountId) -> (u32, u32) {
+			TemplateModule::owned_capacity(&account)
+		}
+
+		fn recipient_remaining_slots(to: AccountId) -> u32 {
+			TemplateModule::recipient_remaining_slots(&to)
+		}
+
+		fn kitty_of_owner_by_index(owner: AccountId, index: u32) -> Option<KittyId> {
+			TemplateModule::kitty_of_owner_by_index(&owner, index)
+		}
+
+		fn floor_listing() -> Option<(KittyId, Balance)> {
+			TemplateModule::floor_listing()
+		}
+
+		fn owned_ids(account: AccountId) -> Vec<KittyId> {
+			TemplateModule::owned_ids(&account)
+		}
+
+		fn listed_value(account: AccountId) -> Balance {
+			TemplateModule::listed_value(&account)
+		}
+
+		fn listings_of(owner: AccountId, start: u32, limit: u32) -> Vec<(KittyId, Balance)> {
+			TemplateModule::listings_of(&owner, start, limit)
+		}
+
+		fn price_history(kitty_id: KittyId) -> Vec<(BlockNumber, Balance)> {
+			TemplateModule::price_history(&kitty_id)
+		}
+
+		fn total_reserved_offers(who: AccountId) -> Balance {
+			TemplateModule::total_reserved_offers(&who)
+		}
+
+		fn snapshot(
+			start: Option<KittyId>,
+			limit: u32,
+		) -> (
+			Vec<(KittyId, pallet_template::KittyDetails<KittyId, AccountId, Balance, BlockNumber>)>,
+			Option<KittyId>,
+		) {
+			TemplateModule::snapshot(start, limit)
+		}
+
+		fn market_summary() -> pallet_template::MarketSummary<Balance> {
+			TemplateModule::market_summary()
+		}
+
+		fn price_or_zero(kitty_id: KittyId) -> Balance {
+			TemplateModule::price_or_zero(&kitty_id)
+		}
+
+		fn name_or_empty(kitty_id: KittyId) -> Vec<u8> {
+			TemplateModule::name_or_empty(&kitty_id)
+		}
+
+		fn dry_run_breed(
+			parent_1: KittyId,
+			parent_2: KittyId,
+		) -> Option<(KittyId, pallet_template::KittyTraits)> {
+			TemplateModule::dry_run_breed(&parent_1, &parent_2)
+		}
+
+		fn config_constants() -> pallet_template::ConfigConstants<Balance, BlockNumber> {
+			TemplateModule::config_constants()
+		}
+
+		fn derived_account(parent: AccountId, index: u16) -> AccountId {
+			TemplateModule::derived_account(&parent, index)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -26,23 +26,27 @@
 // Substrate and Polkadot dependencies
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+	traits::{
+		tokens::Preservation, ConstBool, ConstU128, ConstU16, ConstU32, ConstU64, ConstU8,
+		VariantCountOf,
+	},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
+	PalletId,
 };
 use frame_system::limits::{BlockLength, BlockWeights};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{traits::One, Perbill, Permill};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
 	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
 	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	System, DAYS, EXISTENTIAL_DEPOSIT, MINUTES, SLOT_DURATION, UNIT, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -155,16 +159,66 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	/// Listed Kitties keep their price; the depreciation game mode is disabled.
+	pub const KittyListingDecay: Permill = Permill::zero();
+	pub const KittiesPalletId: PalletId = PalletId(*b"py/kitty");
+	/// Buying a Kitty never reaps the buyer's account.
+	pub const KittyBuyPreservation: Preservation = Preservation::Preserve;
+	/// Burning a Kitty returns 80% of its mint deposit; the rest goes to the fee account.
+	pub const KittyBurnRefundRatio: Permill = Permill::from_percent(80);
+	/// Skipping a Kitty's breeding cooldown costs one unit, paid to the fee account.
+	pub const KittyAccelerateFee: Balance = UNIT;
+	/// Offering a sire costs its breeders at least a hundredth of a unit.
+	pub const KittyMinSireFee: Balance = UNIT / 100;
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type NativeBalance = Balances;
+	type MaxKittiesOwned = ConstU32<100>;
+	type BreedingCooldown = ConstU32<{ 10 * MINUTES }>;
+	type ListingDecay = KittyListingDecay;
+	type MaxDecaysPerBlock = ConstU32<50>;
+	type MintDifficulty = ConstU8<2>;
+	type OnMint = ();
+	type OnSale = ();
+	type MaxBatchSize = ConstU32<20>;
+	type EmitPerItemEvents = ConstBool<true>;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
-	
-	#[doc = " Define o tipo de saldo nativo usado para transações de Kitties."]
-	type NativeBalance;
-	
-	#[doc = " Define o limite máximo de Kitties que um usuário pode possuir."]
-	type MaxKittiesOwned;
+	type MaxNameLength = ConstU32<32>;
+	type NamePolicy = pallet_template::PrintableAscii;
+	type MaxMemo = ConstU32<64>;
+	type MaxScan = ConstU32<1_000>;
+	type BurnDelay = ConstU32<{ 10 * MINUTES }>;
+	type FeedCooldown = ConstU32<{ 60 * MINUTES }>;
+	type MaxListingsPerAccount = ConstU32<50>;
+	type PalletId = KittiesPalletId;
+	type BuyPreservation = KittyBuyPreservation;
+	type InactivityThreshold = ConstU32<{ 365 * DAYS }>;
+	type OnReceive = ();
+	type MaxSupply = ConstU32<1_000_000>;
+	// Minting is free for now, so burns have no deposit to split.
+	type MintDeposit = ConstU128<0>;
+	type BurnRefundRatio = KittyBurnRefundRatio;
+	// Fees accrue in the pallet's fee sub-account until root withdraws them.
+	type FeeResolver = pallet_template::PalletFeeAccount<Runtime>;
+	type AccelerateFee = KittyAccelerateFee;
+	type TraitInheritance = pallet_template::EvenOddSplit;
+	type MaxGeneration = ConstU16<64>;
+	type AllowOpenMint = ConstBool<true>;
+	// No items pallet is deployed yet, so there is nothing to equip.
+	type ItemId = u32;
+	type ItemsInspect = ();
+	type MaxOffersPerKitty = ConstU32<64>;
+	type MaxPricePoints = ConstU32<32>;
+	type PriceUpdateCooldown = ConstU32<{ 5 * MINUTES }>;
+	type DnaFilter = ();
+	type MinSireFee = KittyMinSireFee;
+	type TransferValidator = ();
+	// 32 bytes keeps the storage layout Kitties had before the DNA length became configurable.
+	type DnaLength = ConstU32<32>;
 }
 
 impl pallet_kitties::Config for Runtime {
@@ -168,7 +168,11 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_template::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_template::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_template::migrations::v3::MigrateV2ToV3<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<